@@ -13,6 +13,47 @@ const instructionFunctions = { misc: "", jump: "", load: "", arithmetic: "", bit
 
 let maxInstructionNameLength = 0;
 
+// Instructions that read and write the memory operand, the others only read it.
+const readModifyWriteMnemonics = ["INC", "DEC", "RLC", "RRC", "RL", "RR", "SLA", "SRA", "SWAP", "SRL", "RES", "SET"];
+
+// Classifies the memory accesses of an instruction besides its fetch, and whether it jumps.
+function instructionAccess(instructionName) {
+
+    const [mnemonic, operandList = ""] = instructionName.split(" ");
+    const operands = operandList.split(",");
+
+    // JP (HL) jumps to the address in HL without reading the memory.
+    const isMemory = operand => operand.startsWith("(") && mnemonic !== "JP";
+
+    let read = false;
+    let write = false;
+    let jump = false;
+
+    if (mnemonic === "JP" || mnemonic === "JR") {
+        jump = true;
+    } else if (mnemonic === "CALL" || mnemonic === "RST") {
+        write = true;
+        jump = true;
+    } else if (mnemonic === "RET" || mnemonic === "RETI") {
+        read = true;
+        jump = true;
+    } else if (mnemonic === "PUSH") {
+        write = true;
+    } else if (mnemonic === "POP") {
+        read = true;
+    } else if (mnemonic === "LD" || mnemonic === "LDH") {
+        write = isMemory(operands[0]);
+        read = operands.length > 1 && isMemory(operands[1]);
+    } else {
+        read = operands.some(isMemory);
+        write = read && readModifyWriteMnemonics.includes(mnemonic);
+    }
+
+    const access = [read && "READ", write && "WRITE", jump && "JUMP"].filter(Boolean).join("_");
+
+    return `Access::${access || "NONE"}`;
+}
+
 function processTable(tableId) {
 
     const opcodeTable = document.getElementById(tableId);
@@ -68,24 +109,36 @@ function processTable(tableId) {
                 const backgroundColor = row.cells[j].bgColor;
 
                 let instructionType;
+                let instructionGroup;
 
                 if (backgroundColor === "#ff99cc") {
                     instructionType = "misc";
+                    instructionGroup = "Control";
                 } else if (backgroundColor === "#ffcc99") {
                     instructionType = "jump";
-                } else if (backgroundColor === "#ccccff" || backgroundColor === "#ccffcc") {
+                    instructionGroup = "Jump";
+                } else if (backgroundColor === "#ccccff") {
+                    instructionType = "load";
+                    instructionGroup = "Load8";
+                } else if (backgroundColor === "#ccffcc") {
                     instructionType = "load";
-                } else if (backgroundColor === "#ffff99" || backgroundColor === "#ffcccc") {
+                    instructionGroup = "Load16";
+                } else if (backgroundColor === "#ffff99") {
+                    instructionType = "arithmetic";
+                    instructionGroup = "Arithmetic8";
+                } else if (backgroundColor === "#ffcccc") {
                     instructionType = "arithmetic";
+                    instructionGroup = "Arithmetic16";
                 } else if (backgroundColor === "#80ffff") {
                     instructionType = "bit";
+                    instructionGroup = "Bit";
                 }
 
                 maxInstructionNameLength = Math.max(maxInstructionNameLength, instructionName.length);
 
                 instructionFunctions[instructionType] += instructionFunction(opcode, instructionName, lengthInBytes, durationInCycles, flagsAffected, functionName);
 
-                instructions += `    Instruction::new("${instructionName}", ${lengthInBytes}, ${instructionType}::${functionName}),\n`;
+                instructions += `    Instruction::new("${instructionName}", ${lengthInBytes}, "${durationInCycles}", "${flagsAffected}", Group::${instructionGroup}, ${instructionAccess(instructionName)}, ${instructionType}::${functionName}),\n`;
             } else {
                 instructions += '    UNDEFINED,\n'
             }
@@ -10,48 +10,182 @@ mod arithmetic;
 mod bit;
 
 use crate::Cpu;
-use crate::memory_map::MemoryMap;
+use crate::bus::Bus;
 
 #[derive(Clone, Copy)]
 pub struct Instruction {
     pub name: &'static str,
     pub length: u8,
-    pub function: fn(&mut Cpu, &mut MemoryMap) -> u8,
+    /// T-cycles of the instruction, conditional instructions take these when the condition is false.
+    pub cycles: u8,
+    /// T-cycles of a conditional instruction when the condition is true, the same as \`cycles\` for the others.
+    pub branch_cycles: u8,
+    pub flags: FlagEffects,
+    pub group: Group,
+    pub access: Access,
+    pub function: fn(&mut Cpu, &mut dyn Bus) -> u8,
 }
 
 impl Instruction {
+    /// Creates an instruction with the duration and the flags as they are written in the opcode table.
+    /// # Arguments
+    /// * \`cycles\` - T-cycles like \`8\`, or \`12/8\` for a conditional instruction that takes 12 cycles when the condition is true.
+    ///
+    /// * \`flags\` - Effects on the Z, N, H and C flags like \`Z 0 H -\`.
     #[allow(dead_code)]
-    pub const fn new(name: &'static str, length: u8, function: fn(&mut Cpu, &mut MemoryMap) -> u8) -> Self {
+    pub const fn new(name: &'static str, length: u8, cycles: &str, flags: &str, group: Group, access: Access, function: fn(&mut Cpu, &mut dyn Bus) -> u8) -> Self {
+        let (cycles, branch_cycles) = parse_cycles(cycles);
+
         Self {
             name,
             length,
+            cycles,
+            branch_cycles,
+            flags: FlagEffects::new(flags),
+            group,
+            access,
             function,
         }
     }
+
+    pub fn is_conditional(&self) -> bool {
+        self.cycles != self.branch_cycles
+    }
 }
 
-#[allow(dead_code)]
-fn undefined(_cpu: &mut Cpu, _memory_map: &mut MemoryMap) -> u8 {
+// Parses a duration of the opcode table into the cycles when the condition is false and when it is true.
+const fn parse_cycles(cycles: &str) -> (u8, u8) {
+    let bytes = cycles.as_bytes();
+    let mut values = [0u8; 2];
+    let mut value_index = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'/' {
+            value_index = 1;
+        } else {
+            values[value_index] = values[value_index] * 10 + (bytes[i] - b'0');
+        }
+        i += 1;
+    }
+
+    if value_index == 0 {
+        (values[0], values[0])
+    } else {
+        (values[1], values[0])
+    }
+}
+
+/// How an instruction changes a flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlagEffect {
+    Unaffected,
+    Reset,
+    Set,
+    /// Depends on the result of the instruction.
+    Affected,
+}
+
+impl FlagEffect {
+    const fn new(effect: u8) -> Self {
+        match effect {
+            b'-' => Self::Unaffected,
+            b'0' => Self::Reset,
+            b'1' => Self::Set,
+            _ => Self::Affected,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlagEffects {
+    pub zero: FlagEffect,
+    pub subtract: FlagEffect,
+    pub half_carry: FlagEffect,
+    pub carry: FlagEffect,
+}
+
+impl FlagEffects {
+    const fn new(effects: &str) -> Self {
+        let effects = effects.as_bytes();
+
+        Self {
+            zero: FlagEffect::new(effects[0]),
+            subtract: FlagEffect::new(effects[2]),
+            half_carry: FlagEffect::new(effects[4]),
+            carry: FlagEffect::new(effects[6]),
+        }
+    }
+}
+
+/// Instruction groups as they are colored in the opcode table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Group {
+    /// NOP, STOP, HALT, DI, EI, the CB prefix and the undefined opcodes.
+    Control,
+    /// Jumps, calls, returns and restarts.
+    Jump,
+    Load8,
+    Load16,
+    Arithmetic8,
+    Arithmetic16,
+    /// Rotates, shifts and the bit instructions.
+    Bit,
+}
+
+/// Memory accesses of an instruction besides its fetch, and whether it changes the program counter.
+/// Stack accesses of PUSH, POP, calls and returns are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Access(u8);
+
+impl Access {
+    pub const NONE: Self = Self(0x0);
+
+    pub const READ: Self = Self(0x1);
+    pub const WRITE: Self = Self(0x2);
+    pub const JUMP: Self = Self(0x4);
+
+    pub const READ_WRITE: Self = Self(0x3);
+    pub const READ_JUMP: Self = Self(0x5);
+    pub const WRITE_JUMP: Self = Self(0x6);
+
+    pub fn reads_memory(self) -> bool {
+        self.0 & Self::READ.0 != 0
+    }
+
+    pub fn writes_memory(self) -> bool {
+        self.0 & Self::WRITE.0 != 0
+    }
+
+    pub fn is_jump(self) -> bool {
+        self.0 & Self::JUMP.0 != 0
+    }
+}
+
+/// Opcodes without an instruction(0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD). <br>
+/// On hardware executing them hangs the cpu until the gameboy is reset.
+fn undefined(cpu: &mut Cpu, _bus: &mut dyn Bus) -> u8 {
+    cpu.lock();
     4
-} 
+}
 
-const UNDEFINED: Instruction = Instruction::new("UNDEFINED", 1, undefined);
+const UNDEFINED: Instruction = Instruction::new("UNDEFINED", 1, "4", "- - - -", Group::Control, Access::NONE, undefined);
 
 #[allow(dead_code)]
 pub const MAX_INSTRUCTION_NAME_LENGTH: usize = ${maxInstructionNameLength};
 
+#[rustfmt::skip]
 pub const INSTRUCTIONS: [Instruction; 0x100] = [
-${instructions}
-];
+${instructions}];
 
+#[rustfmt::skip]
 pub const PREFIX_CB_INSTRUCTIONS: [Instruction; 0x100] = [
-${prefixCBInstructions}
-];`
+${prefixCBInstructions}];`
 }
 
 function instructionFunctionFile(instructionFunctions) {
     return`#![allow(dead_code, unused_variables)]
-use crate::memory_map::MemoryMap;
+use crate::bus::Bus;
 use crate::Cpu;
 ${instructionFunctions}`
 }
@@ -66,7 +200,7 @@ function instructionFunction(opcode, name, lengthInBytes, durationInCycles, flag
 ///  Length in bytes: ${lengthInBytes} <br>
 ///  Duration in cycles: ${durationInCycles} <br>
 ///  Flags affected: ${flagsAffected}
-pub fn ${functionName}(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ${functionName}(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     ${returningCycle}
 }\n`
 }
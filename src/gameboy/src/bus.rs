@@ -0,0 +1,106 @@
+use super::{cpu::Cpu, memory_map::OamCorruption};
+
+/// Memory bus as seen by the cpu.
+/// Every read and write takes one M-cycle and the rest of the hardware advances with it,
+/// so instructions access the memory on the same M-cycles as the real cpu.
+pub trait Bus {
+    /// Reads a byte in the next M-cycle.
+    fn read(&mut self, address: u16) -> u8;
+
+    /// Writes a byte in the next M-cycle.
+    fn write(&mut self, address: u16, value: u8);
+
+    /// Spends an M-cycle without accessing the memory.
+    /// Used for the internal M-cycles of the instructions.
+    fn tick(&mut self);
+
+    /// Reads the cpu internal state such as IE and IF without spending an M-cycle.
+    fn get(&self, address: u16) -> u8;
+
+    /// Writes the cpu internal state such as IF without spending an M-cycle.
+    fn set(&mut self, address: u16, value: u8);
+
+    fn read_u16(&mut self, address: u16) -> u16 {
+        let lsb = self.read(address) as u16; // Get the least significant byte
+        let msb = self.read(address.wrapping_add(1)) as u16; // Get the most significant byte
+
+        (msb << 8) | lsb
+    }
+
+    fn write_u16(&mut self, address: u16, value: u16) {
+        let lsb = (value & 0xFF) as u8; // Get the least significant byte
+        let msb = ((value >> 8) & 0xFF) as u8; // Get the most significant byte
+
+        self.write(address, lsb);
+        self.write(address.wrapping_add(1), msb);
+    }
+
+    /// Called before the cpu executes the instruction at its PC, with the registers it starts with.
+    /// Used for tracing the execution.
+    fn on_instruction(&mut self, _cpu: &Cpu) {}
+
+    // OAM corruption bug is only present on a bus with a PPU.
+    fn try_corrupt_oam(&mut self, _address: u16, _corruption: OamCorruption) {}
+    fn enable_oam_corruption(&mut self) {}
+    fn disable_oam_corruption(&mut self) {}
+}
+
+/// Activity of the bus in an M-cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusCycle {
+    Read(u16, u8),
+    Write(u16, u8),
+    Idle,
+}
+
+/*
+    A flat 64KB memory without any hardware behind it, for testing the cpu on its own.
+    There is no PPU locking, OAM corruption, memory mapped IO or cartridge, every address is a plain byte.
+    Every M-cycle is recorded so instructions can be compared with the bus activity of the real cpu.
+*/
+#[derive(Clone)]
+pub struct FlatBus {
+    pub memory: Box<[u8; 0x10000]>,
+    pub cycles: Vec<BusCycle>,
+}
+
+impl FlatBus {
+    pub fn new() -> Self {
+        Self {
+            memory: Box::new([0; 0x10000]),
+            cycles: Vec::new(),
+        }
+    }
+}
+
+impl Default for FlatBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bus for FlatBus {
+    fn read(&mut self, address: u16) -> u8 {
+        let value = self.memory[address as usize];
+        self.cycles.push(BusCycle::Read(address, value));
+
+        value
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        self.memory[address as usize] = value;
+        self.cycles.push(BusCycle::Write(address, value));
+    }
+
+    fn tick(&mut self) {
+        self.cycles.push(BusCycle::Idle);
+    }
+
+    fn get(&self, address: u16) -> u8 {
+        self.memory[address as usize]
+    }
+
+    fn set(&mut self, address: u16, value: u8) {
+        self.memory[address as usize] = value;
+    }
+}
@@ -1,9 +1,20 @@
 use super::{
+    bus::Bus,
     instructions::{Instruction, INSTRUCTIONS, PREFIX_CB_INSTRUCTIONS},
-    memory_map::{Io, MemoryMap, OamCorruption},
+    memory_map::{Io, OamCorruption},
     registers::Registers,
 };
 
+/// Power state of the cpu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuState {
+    Running,
+    Halted,
+    Stopped,
+    /// Cpu executed an illegal opcode at the given address and hangs until it is reset.
+    Locked(u16),
+}
+
 #[derive(Clone)]
 pub struct Cpu {
     pub pc: u16,
@@ -12,8 +23,14 @@ pub struct Cpu {
     pub registers: Registers,
     pub ime: bool,
 
+    // EI sets the IME flag only after the next instruction is executed.
+    ime_scheduled: bool,
+
     halt_mode: bool,
+    halt_bug: bool,
     stop_mode: bool,
+
+    locked_pc: Option<u16>,
 }
 
 impl Cpu {
@@ -25,8 +42,13 @@ impl Cpu {
             registers: Registers::new(),
             ime: false,
 
+            ime_scheduled: false,
+
             halt_mode: false,
+            halt_bug: false,
             stop_mode: false,
+
+            locked_pc: None,
         }
     }
 
@@ -38,51 +60,85 @@ impl Cpu {
             registers: Registers::after_boot(),
             ime: false,
 
+            ime_scheduled: false,
+
             halt_mode: false,
+            halt_bug: false,
             stop_mode: false,
+
+            locked_pc: None,
         }
     }
 
-    pub fn cycle(&mut self, memory_map: &mut MemoryMap) {
-        if self.handle_interrupts(memory_map) {
-            return;
+    /// Runs an instruction or an interrupt dispatch.
+    /// Returns false when no instruction is executed, the cpu is halted, stopped or locked or it dispatched an interrupt.
+    pub fn cycle(&mut self, bus: &mut dyn Bus) -> bool {
+        // A locked cpu doesn't respond to interrupts.
+        if self.locked_pc.is_some() {
+            return false;
+        }
+
+        if self.handle_interrupts(bus) {
+            return false;
         }
 
-        // TODO: https://gbdev.io/pandocs/halt.html?highlight=halt#halt-bug
         // In halt mode CPU is powered down until an interrupt occurs.
         if self.halt_mode || self.stop_mode {
-            return;
+            return false;
         }
 
+        // IME is set by EI only after the instruction following it has been executed.
+        let ime_scheduled = self.ime_scheduled;
+
+        bus.on_instruction(self);
+
         let instruction = {
-            let opcode = Self::fetch(self.pc, memory_map);
-            memory_map.mem_syncer.open_sync();
+            let opcode = Self::fetch(self.pc, bus);
 
             if opcode == 0xCB {
-                PREFIX_CB_INSTRUCTIONS[Self::fetch(self.pc + 1, memory_map) as usize]
+                PREFIX_CB_INSTRUCTIONS[Self::fetch(self.pc.wrapping_add(1), bus) as usize]
             } else {
                 INSTRUCTIONS[opcode as usize]
             }
         };
 
-        self.execute(instruction, memory_map);
-        memory_map.mem_syncer.close_sync();
+        self.execute(instruction, bus);
+
+        // Instruction may have been a DI, which cancels the scheduled EI.
+        if ime_scheduled && self.ime_scheduled {
+            self.ime_scheduled = false;
+            self.ime = true;
+        }
+
+        true
     }
 
-    fn fetch(pc: u16, memory_map: &MemoryMap) -> u8 {
-        memory_map.cpu_get(pc)
+    fn fetch(pc: u16, bus: &mut dyn Bus) -> u8 {
+        bus.read(pc)
     }
 
-    fn execute(&mut self, instruction: Instruction, memory_map: &mut MemoryMap) {
+    fn execute(&mut self, instruction: Instruction, bus: &mut dyn Bus) {
         self.pc += instruction.length as u16;
 
-        let instruction_cycles = (instruction.function)(self, memory_map) as u32;
+        if self.halt_bug {
+            /*
+                From pandocs:
+                The byte after halt is read twice, because the CPU fails to increment PC.
+                Immediate operands are read relative to PC so they are also shifted by one byte.
+            */
+            self.halt_bug = false;
+            self.pc -= 1;
+        }
+
+        // Internal M-cycles at the end of an instruction are left to the caller.
+        // It is expected to tick the bus until the returned cycles have passed.
+        let instruction_cycles = (instruction.function)(self, bus) as u32;
 
-        self.clock_cycles += instruction_cycles;
-        // self.clock_cycles = self.clock_cycles.wrapping_add(instruction_cycles);
+        // Clock cycles of the cpu wrap around, they are only compared with wrapping arithmetic.
+        self.clock_cycles = self.clock_cycles.wrapping_add(instruction_cycles);
     }
 
-    fn handle_interrupt(&self, memory_map: &MemoryMap) -> Option<(u16, u8)> {
+    fn pending_interrupt(bus: &dyn Bus) -> Option<u8> {
         /*
             From pandocs:
             Provided that IME and IE allow the execution of more than one of the requested interrupts,
@@ -91,67 +147,86 @@ impl Cpu {
             Bit 0 (V-Blank) having the highest priority, and Bit 4 (Joypad) having the lowest priority.
         */
 
-        let ie_reg = memory_map.cpu_get_io(Io::IE);
-        let if_reg = memory_map.cpu_get_io(Io::IF);
-        let interrupt = ie_reg & if_reg;
-
-        if interrupt & 0x1 != 0 {
-            // V-Blank interrupt
-            // println!("VBLANK");
-            return Some((0x40, if_reg & 0x1E));
-        } else if interrupt & 0x2 != 0 {
-            // LCD STAT interrupt
-            return Some((0x48, if_reg & 0xFD));
-        } else if interrupt & 0x4 != 0 {
-            // Timer interrupt
-            return Some((0x50, if_reg & 0xFB));
-        } else if interrupt & 0x8 != 0 {
-            // Serial interrupt
-            return Some((0x58, if_reg & 0xF7));
-        } else if interrupt & 0x10 != 0 {
-            // Joypad interrupt
-            return Some((0x60, if_reg & 0xEF));
-        }
+        let ie_reg = bus.get(Io::IE as _);
+        let if_reg = bus.get(Io::IF as _);
+        let interrupt = ie_reg & if_reg & 0x1F;
 
-        return None;
+        if interrupt != 0 {
+            Some(interrupt.trailing_zeros() as u8)
+        } else {
+            None
+        }
     }
 
-    fn handle_interrupts(&mut self, memory_map: &mut MemoryMap) -> bool {
-        if let Some((interrupt_address, new_if_reg)) = self.handle_interrupt(&memory_map) {
-            // Halt mode is disabled regardless of whether the interrupt is handled or not.
-            self.halt_mode = false;
-            self.stop_mode = false;
+    fn handle_interrupts(&mut self, bus: &mut dyn Bus) -> bool {
+        if Self::pending_interrupt(bus).is_none() {
+            return false;
+        }
 
-            if !self.ime {
-                return false;
-            }
+        // Halt mode is disabled regardless of whether the interrupt is handled or not.
+        // Stop mode is only exited with a joypad input.
+        if self.stop_mode {
+            return false;
+        }
+
+        // Halted cpu sees the interrupt in the M-cycle it is requested, the dispatch starts right after it.
+        self.halt_mode = false;
 
-            self.ime = false;
+        if !self.ime {
+            return false;
+        }
+
+        self.ime = false;
+        self.ime_scheduled = false;
+
+        if self.halt_bug {
+            // EI followed by a bugged HALT returns to the HALT itself after the interrupt.
+            self.halt_bug = false;
+            self.pc -= 1;
+        }
+
+        /*
+            The interrupt dispatch takes 5 M-cycles:
+            2 wait states, high byte of PC is pushed, low byte of PC is pushed and PC is set to the vector.
+            The vector is decided after the high byte push. If that push overwrote IE at 0xFFFF
+            and the interrupt is no longer enabled, the dispatch is cancelled and PC is set to 0x0000.
+        */
+        bus.tick();
+        bus.tick();
 
-            self.call(memory_map, interrupt_address);
+        self.sp = self.sp.wrapping_sub(1);
+        bus.write(self.sp, (self.pc >> 8) as u8);
 
-            // Replace the if flag.
-            memory_map.cpu_set_io(Io::IF, new_if_reg);
+        let interrupt = Self::pending_interrupt(bus);
 
-            // TODO: "The entire routine should last a total of 5 M-cycles." 5 M-cycles =? 20
-            self.clock_cycles = self.clock_cycles.wrapping_add(20);
+        self.sp = self.sp.wrapping_sub(1);
+        bus.write(self.sp, (self.pc & 0xFF) as u8);
 
-            true
+        self.pc = if let Some(interrupt) = interrupt {
+            // Acknowledge the interrupt by clearing its IF bit.
+            bus.set(Io::IF as _, bus.get(Io::IF as _) & !(1 << interrupt));
+            0x40 + interrupt as u16 * 8
         } else {
-            false
-        }
+            0x0000
+        };
+
+        bus.tick();
+
+        self.clock_cycles = self.clock_cycles.wrapping_add(20);
+
+        true
     }
 
     ///////////////////////////////////////////////////
 
     // Get immediate byte after an instruction.
-    pub fn get_immediate_u8(&self, memory_map: &MemoryMap) -> u8 {
-        memory_map.cpu_get(self.pc - 1)
+    pub fn get_immediate_u8(&self, bus: &mut dyn Bus) -> u8 {
+        bus.read(self.pc.wrapping_sub(1))
     }
 
     // Get immediate two bytes after an instruction.
-    pub fn get_immediate_u16(&self, memory_map: &MemoryMap) -> u16 {
-        memory_map.cpu_get_u16(self.pc - 2)
+    pub fn get_immediate_u16(&self, bus: &mut dyn Bus) -> u16 {
+        bus.read_u16(self.pc.wrapping_sub(2))
     }
 
     pub fn add(&mut self, rhs: u8) {
@@ -367,27 +442,36 @@ impl Cpu {
         val & !(1 << bit)
     }
 
-    pub fn push(&mut self, memory_map: &mut MemoryMap, value: u16) {
-        memory_map.try_corrupt_oam(self.sp, OamCorruption::Read);
-        self.sp -= 2;
-        memory_map.cpu_set_u16(self.sp, value);
+    // Push takes 3 M-cycles: SP is decremented in an internal M-cycle, then high and low bytes are written.
+    // OAM is corrupted in the first three M-cycles of the instruction but not by the last write.
+    pub fn push(&mut self, bus: &mut dyn Bus, value: u16) {
+        bus.try_corrupt_oam(self.sp, OamCorruption::Read);
+        bus.tick();
+        bus.try_corrupt_oam(self.sp, OamCorruption::Write);
+
+        self.sp = self.sp.wrapping_sub(1);
+        bus.write(self.sp, (value >> 8) as u8); // Write the most significant byte
+        self.sp = self.sp.wrapping_sub(1);
+        bus.disable_oam_corruption();
+        bus.write(self.sp, (value & 0xFF) as u8); // Write the least significant byte
+        bus.enable_oam_corruption();
     }
 
-    pub fn pop(&mut self, memory_map: &mut MemoryMap) -> u16 {
-        memory_map.disable_oam_corruption();
-        memory_map.try_corrupt_oam(self.sp, OamCorruption::IncDecRead);
-        let lsb = memory_map.cpu_get(self.sp) as u16; // Get the least significant byte
-        memory_map.try_corrupt_oam(self.sp + 1, OamCorruption::Read);
-        let msb = memory_map.cpu_get(self.sp + 1) as u16; // Get the most significant byte
-        memory_map.enable_oam_corruption();
+    pub fn pop(&mut self, bus: &mut dyn Bus) -> u16 {
+        bus.disable_oam_corruption();
+        bus.try_corrupt_oam(self.sp, OamCorruption::IncDecRead);
+        let lsb = bus.read(self.sp) as u16; // Get the least significant byte
+        bus.try_corrupt_oam(self.sp.wrapping_add(1), OamCorruption::Read);
+        let msb = bus.read(self.sp.wrapping_add(1)) as u16; // Get the most significant byte
+        bus.enable_oam_corruption();
 
-        self.sp += 2;
+        self.sp = self.sp.wrapping_add(2);
 
         lsb | (msb << 8)
     }
 
-    pub fn call(&mut self, memory_map: &mut MemoryMap, address: u16) {
-        self.push(memory_map, self.pc);
+    pub fn call(&mut self, bus: &mut dyn Bus, address: u16) {
+        self.push(bus, self.pc);
         self.pc = address;
     }
 
@@ -399,24 +483,84 @@ impl Cpu {
     */
 
     pub fn enable_interrupts(&mut self) {
-        // memory_map.set(0xFFFF, 0x1F);
         self.ime = true;
     }
 
+    pub fn enable_interrupts_delayed(&mut self) {
+        self.ime_scheduled = true;
+    }
+
     pub fn disable_interrupts(&mut self) {
-        // memory_map.set(0xFFFF, 0x00);
         self.ime = false;
+        self.ime_scheduled = false;
+    }
+
+    pub fn halt(&mut self, bus: &dyn Bus) {
+        /*
+            From pandocs:
+            If IME is not set and an interrupt is already pending when HALT is executed,
+            the CPU doesn't enter halt mode and the halt bug is triggered instead.
+        */
+        if !self.ime && Self::pending_interrupt(bus).is_some() {
+            self.halt_bug = true;
+        } else {
+            self.halt_mode = true;
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halt_mode
     }
 
-    pub fn halt(&mut self) {
-        self.halt_mode = true;
+    pub fn stop(&mut self, bus: &mut dyn Bus) {
+        /*
+            From pandocs:
+            Button held | Interrupt pending | Result
+            Yes         | Yes               | STOP is a 1-byte opcode, mode doesn't change, DIV isn't reset.
+            Yes         | No                | STOP is a 2-byte opcode, HALT mode is entered, DIV isn't reset.
+            No          | Yes               | STOP is a 1-byte opcode, STOP mode is entered, DIV is reset.
+            No          | No                | STOP is a 2-byte opcode, STOP mode is entered, DIV is reset.
+        */
+        let button_held = bus.get(Io::JOYP as _) & 0xF != 0xF;
+        let interrupt_pending = Self::pending_interrupt(bus).is_some();
+
+        if !interrupt_pending {
+            // The byte after STOP is skipped.
+            self.pc = self.pc.wrapping_add(1);
+        }
+
+        if button_held {
+            self.halt_mode = !interrupt_pending;
+        } else {
+            self.stop_mode = true;
+            bus.set(Io::DIV as _, 0);
+        }
     }
 
-    // pub fn stop(&mut self) {
-    //     self.stop_mode = true;
-    // }
+    // Stop mode is exited when one of the selected joypad lines goes low.
+    pub fn exit_stop_mode(&mut self) {
+        self.stop_mode = false;
+    }
 
     pub fn is_stopped(&self) -> bool {
         self.stop_mode
     }
+
+    // Illegal opcodes hang the cpu. Only way to recover is to reset the gameboy.
+    pub fn lock(&mut self) {
+        // PC is already incremented past the 1 byte illegal opcode.
+        self.locked_pc = Some(self.pc.wrapping_sub(1));
+    }
+
+    pub fn state(&self) -> CpuState {
+        if let Some(locked_pc) = self.locked_pc {
+            CpuState::Locked(locked_pc)
+        } else if self.stop_mode {
+            CpuState::Stopped
+        } else if self.halt_mode {
+            CpuState::Halted
+        } else {
+            CpuState::Running
+        }
+    }
 }
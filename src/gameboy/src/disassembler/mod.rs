@@ -0,0 +1,310 @@
+mod rom;
+
+use std::fmt::{self, Display};
+
+use arrayvec::ArrayVec;
+
+use crate::instructions::{FlagEffects, Instruction, INSTRUCTIONS, PREFIX_CB_INSTRUCTIONS};
+
+pub use self::rom::{disassemble_rom, RomDisassembly};
+
+/*
+    Disassembler that decodes an instruction into its mnemonic and typed operands.
+    Operands are read from the instruction names, immediates are filled in from the bytes after the opcode
+    and relative jumps are resolved to their target address.
+    Instructions are written in the RGBDS syntax:
+        ld bc, $1234    jr nz, $0150    ldh [$ff44], a    ld a, [hl+]    bit 7, h
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+    E,
+    H,
+    L,
+    AF,
+    BC,
+    DE,
+    HL,
+    SP,
+}
+
+impl Register {
+    pub fn is_16bit(self) -> bool {
+        matches!(self, Self::AF | Self::BC | Self::DE | Self::HL | Self::SP)
+    }
+
+    fn rgbds_name(self) -> &'static str {
+        match self {
+            Self::A => "a",
+            Self::B => "b",
+            Self::C => "c",
+            Self::D => "d",
+            Self::E => "e",
+            Self::H => "h",
+            Self::L => "l",
+            Self::AF => "af",
+            Self::BC => "bc",
+            Self::DE => "de",
+            Self::HL => "hl",
+            Self::SP => "sp",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    NotZero,
+    Zero,
+    NotCarry,
+    Carry,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    /// Memory at the address in a register pair: (BC), (DE) and (HL).
+    Indirect(Register),
+    /// (HL+), HL is incremented after the access.
+    IndirectIncrement,
+    /// (HL-), HL is decremented after the access.
+    IndirectDecrement,
+    /// (C), memory at 0xFF00 + C.
+    HighIndirect,
+    /// d8
+    Immediate8(u8),
+    /// d16
+    Immediate16(u16),
+    /// (a16)
+    Address(u16),
+    /// (a8), memory at 0xFF00 + a8.
+    HighAddress(u8),
+    /// r8 of ADD SP,r8.
+    Signed(i8),
+    /// SP+r8 of LD HL,SP+r8.
+    StackOffset(i8),
+    /// Address a jump or a call goes to. Relative jumps are resolved from the address after the instruction.
+    Target(u16),
+    Condition(Condition),
+    /// Bit number of BIT, RES and SET.
+    Bit(u8),
+    /// Address of a RST instruction.
+    Vector(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisassembledInstruction {
+    pub address: u16,
+    /// Opcode and the immediate bytes after it.
+    pub bytes: ArrayVec<u8, 3>,
+    /// Upper case mnemonic like in the instruction names, `UNDEFINED` for the opcodes without an instruction.
+    pub mnemonic: &'static str,
+    pub operands: ArrayVec<Operand, 2>,
+    pub length: u8,
+    /// T-cycles of the instruction, conditional ones take these when the condition is false.
+    pub cycles: u8,
+    /// T-cycles of a conditional instruction when the condition is true.
+    pub taken_cycles: Option<u8>,
+    pub flags: FlagEffects,
+}
+
+impl DisassembledInstruction {
+    pub fn is_undefined(&self) -> bool {
+        self.mnemonic == "UNDEFINED"
+    }
+
+    /// Address the instruction jumps or calls to, if it is known without running it.
+    pub fn target(&self) -> Option<u16> {
+        self.operands.iter().find_map(|operand| match *operand {
+            Operand::Target(address) => Some(address),
+            Operand::Vector(address) => Some(address as u16),
+            _ => None,
+        })
+    }
+}
+
+/// Decodes the instruction at the address.
+/// # Arguments
+/// * `address` - Starting address of the instruction.
+///
+/// * `read` - Reads a byte of memory without any side effects.
+pub fn disassemble(address: u16, read: impl Fn(u16) -> u8) -> DisassembledInstruction {
+    let opcode = read(address);
+
+    let instruction = if opcode == 0xCB {
+        PREFIX_CB_INSTRUCTIONS[read(address.wrapping_add(1)) as usize]
+    } else {
+        INSTRUCTIONS[opcode as usize]
+    };
+
+    let bytes: ArrayVec<u8, 3> = (0..instruction.length as u16)
+        .map(|offset| read(address.wrapping_add(offset)))
+        .collect();
+
+    // Immediates are little endian, the opcode is not a part of them.
+    let immediate = bytes[1..]
+        .iter()
+        .rev()
+        .fold(0, |immediate, &byte| (immediate << 8) | byte as u16);
+
+    let (mnemonic, operand_names) = split_name(&instruction);
+
+    let operands: ArrayVec<Operand, 2> = operand_names
+        .map(|name| decode_operand(mnemonic, name, address, &instruction, immediate))
+        .collect();
+
+    DisassembledInstruction {
+        address,
+        bytes,
+        mnemonic,
+        operands,
+        length: instruction.length,
+        cycles: instruction.cycles,
+        taken_cycles: instruction
+            .is_conditional()
+            .then_some(instruction.branch_cycles),
+        flags: instruction.flags,
+    }
+}
+
+// Splits an instruction name like `LD A,(HL+)` into the mnemonic and the names of its operands.
+fn split_name(instruction: &Instruction) -> (&'static str, impl Iterator<Item = &'static str>) {
+    let name = instruction.name;
+
+    let (mnemonic, operands) = match name {
+        // Zero after STOP is the second byte on hardware, it is not an operand.
+        "STOP 0" | "PREFIX CB" => (name.split(' ').next().unwrap(), ""),
+        _ => name.split_once(' ').unwrap_or((name, "")),
+    };
+
+    (
+        mnemonic,
+        operands.split(',').filter(|operand| !operand.is_empty()),
+    )
+}
+
+fn decode_operand(
+    mnemonic: &'static str,
+    name: &'static str,
+    address: u16,
+    instruction: &Instruction,
+    immediate: u16,
+) -> Operand {
+    let is_jump = matches!(mnemonic, "JR" | "JP" | "CALL" | "RET");
+
+    match name {
+        "A" => Operand::Register(Register::A),
+        "B" => Operand::Register(Register::B),
+        // C is the carry condition in jumps, calls and returns.
+        "C" if is_jump => Operand::Condition(Condition::Carry),
+        "C" => Operand::Register(Register::C),
+        "D" => Operand::Register(Register::D),
+        "E" => Operand::Register(Register::E),
+        "H" => Operand::Register(Register::H),
+        "L" => Operand::Register(Register::L),
+        "AF" => Operand::Register(Register::AF),
+        "BC" => Operand::Register(Register::BC),
+        "DE" => Operand::Register(Register::DE),
+        "HL" => Operand::Register(Register::HL),
+        "SP" => Operand::Register(Register::SP),
+        "(BC)" => Operand::Indirect(Register::BC),
+        "(DE)" => Operand::Indirect(Register::DE),
+        // JP (HL) jumps to the address in HL, it doesn't read the memory.
+        "(HL)" if mnemonic == "JP" => Operand::Register(Register::HL),
+        "(HL)" => Operand::Indirect(Register::HL),
+        "(HL+)" => Operand::IndirectIncrement,
+        "(HL-)" => Operand::IndirectDecrement,
+        "(C)" => Operand::HighIndirect,
+        "NZ" => Operand::Condition(Condition::NotZero),
+        "Z" => Operand::Condition(Condition::Zero),
+        "NC" => Operand::Condition(Condition::NotCarry),
+        "d8" => Operand::Immediate8(immediate as u8),
+        "d16" => Operand::Immediate16(immediate),
+        "a16" => Operand::Target(immediate),
+        "(a16)" => Operand::Address(immediate),
+        "(a8)" => Operand::HighAddress(immediate as u8),
+        // Relative jumps are from the address after the instruction.
+        "r8" if is_jump => Operand::Target(
+            address
+                .wrapping_add(instruction.length as u16)
+                .wrapping_add(immediate as i8 as u16),
+        ),
+        "r8" => Operand::Signed(immediate as i8),
+        "SP+r8" => Operand::StackOffset(immediate as i8),
+        // RST vectors are named like 38H.
+        _ if mnemonic == "RST" => {
+            Operand::Vector(u8::from_str_radix(name.trim_end_matches('H'), 16).unwrap())
+        }
+        _ if matches!(mnemonic, "BIT" | "RES" | "SET") => Operand::Bit(name.parse().unwrap()),
+        _ => unreachable!("unknown operand {name} in {}", instruction.name),
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Register(register) => write!(f, "{}", register.rgbds_name()),
+            Self::Indirect(register) => write!(f, "[{}]", register.rgbds_name()),
+            Self::IndirectIncrement => write!(f, "[hl+]"),
+            Self::IndirectDecrement => write!(f, "[hl-]"),
+            Self::HighIndirect => write!(f, "[c]"),
+            Self::Immediate8(value) => write!(f, "${value:02x}"),
+            Self::Immediate16(value) | Self::Target(value) => write!(f, "${value:04x}"),
+            Self::Address(address) => write!(f, "[${address:04x}]"),
+            Self::HighAddress(address) => write!(f, "[$ff{address:02x}]"),
+            Self::Signed(value) => write!(f, "{value}"),
+            Self::StackOffset(offset) => write!(f, "sp{offset:+}"),
+            Self::Condition(condition) => write!(
+                f,
+                "{}",
+                match condition {
+                    Condition::NotZero => "nz",
+                    Condition::Zero => "z",
+                    Condition::NotCarry => "nc",
+                    Condition::Carry => "c",
+                }
+            ),
+            Self::Bit(bit) => write!(f, "{bit}"),
+            Self::Vector(address) => write!(f, "${address:02x}"),
+        }
+    }
+}
+
+impl DisassembledInstruction {
+    /// Writes the instruction in the RGBDS syntax with the jump or call target written as a label.
+    /// Opcodes without an instruction are written as data, so the output still assembles to the same bytes.
+    pub fn write_rgbds(&self, f: &mut impl fmt::Write, target_label: Option<&str>) -> fmt::Result {
+        if self.is_undefined() {
+            return write!(f, "db ${:02x}", self.bytes[0]);
+        }
+
+        // RGBDS writes the accesses to 0xFF00 + C with LDH.
+        if self.operands.contains(&Operand::HighIndirect) {
+            f.write_str("ldh")?;
+        } else {
+            for character in self.mnemonic.chars() {
+                write!(f, "{}", character.to_ascii_lowercase())?;
+            }
+        }
+
+        for (index, operand) in self.operands.iter().enumerate() {
+            f.write_str(if index == 0 { " " } else { ", " })?;
+
+            match (operand, target_label) {
+                (Operand::Target(_), Some(label)) => f.write_str(label)?,
+                _ => write!(f, "{operand}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for DisassembledInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_rgbds(f, None)
+    }
+}
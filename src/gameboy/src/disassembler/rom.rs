@@ -0,0 +1,640 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io,
+    path::Path,
+};
+
+use super::{disassemble, DisassembledInstruction, Operand, Register};
+
+/*
+    Static disassembler that traces the code of a rom and writes it as an RGBDS project.
+    Code is found by following the jumps, calls and returns from the entry points: 0x100, the RST and the interrupt vectors.
+    Bytes that are not reached are written as data, so the project assembles back to the same rom.
+
+    Bank 0 is at 0x0000-0x3FFF and the other banks are switched in at 0x4000-0x7FFF.
+    Jumps from bank 0 to the switchable area go to the bank that is selected with `ld a, n` and a write to 0x2000-0x3FFF
+    before them. When the bank isn't known from the code, the jump is written with its address and it is not traced.
+
+    Jump tables are found in two forms:
+        - Tables after the calls to a routine that pops the return address to HL and jumps to an entry with JP HL.
+          Games usually call it with a RST instruction.
+        - Tables that are loaded with `ld hl, n16` and read before a JP HL.
+    The length of a table isn't known, entries are read while they point to code.
+    Tables are read after the code that is found without them, so a table ends where the code after it starts.
+*/
+
+const BANK_SIZE: usize = 0x4000;
+
+// Addresses the cpu starts running from and the names of their labels.
+const ENTRY_POINTS: [(u16, &str); 14] = [
+    (0x0000, "RST_00"),
+    (0x0008, "RST_08"),
+    (0x0010, "RST_10"),
+    (0x0018, "RST_18"),
+    (0x0020, "RST_20"),
+    (0x0028, "RST_28"),
+    (0x0030, "RST_30"),
+    (0x0038, "RST_38"),
+    (0x0040, "VBlankInterrupt"),
+    (0x0048, "LCDCInterrupt"),
+    (0x0050, "TimerInterrupt"),
+    (0x0058, "SerialInterrupt"),
+    (0x0060, "JoypadInterrupt"),
+    (0x0100, "Boot"),
+];
+
+// Jump table entries that point before the end of the cartridge header are not code.
+const HEADER_END: u16 = 0x150;
+
+const MAX_JUMP_TABLE_LENGTH: u16 = 256;
+
+// Instructions that are searched for the POP HL and JP HL of a jump table routine.
+const MAX_JUMP_TABLE_ROUTINE_LENGTH: usize = 16;
+
+// Runs of the same byte that are at least this long are written with `ds`.
+const MIN_FILL_LENGTH: usize = 16;
+
+const DATA_BYTES_PER_LINE: usize = 16;
+
+/// What a byte of the rom is found to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Byte {
+    /// Not reached by the code.
+    Data,
+    /// First byte of an instruction.
+    Instruction,
+    /// Immediate bytes of an instruction.
+    Operand,
+    /// First byte of a jump table entry.
+    Pointer,
+    PointerHigh,
+}
+
+/// Labels are named by how the code reaches them. Earlier kinds are preferred when a label is reached in many ways.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum LabelKind {
+    EntryPoint(&'static str),
+    Call,
+    Jump,
+}
+
+/// Location of the code with the bank it runs in.
+#[derive(Debug, Clone, Copy)]
+struct Location {
+    bank: usize,
+    address: u16,
+    /// Bank at 0x4000-0x7FFF when the code runs, if it is known.
+    switched_bank: Option<usize>,
+}
+
+/// Code and data of a rom that are found by tracing the code from the entry points.
+pub struct RomDisassembly<'a> {
+    rom: &'a [u8],
+    bytes: Vec<Byte>,
+    labels: BTreeMap<usize, LabelKind>,
+    // Rom offsets that the jumps, calls and jump table entries go to, by the offset of the instruction or the entry.
+    targets: HashMap<usize, usize>,
+}
+
+struct Tracer<'a> {
+    disassembly: RomDisassembly<'a>,
+    code: Vec<Location>,
+    jump_tables: Vec<Location>,
+    // Whether the routine at a rom offset reads a jump table after its call.
+    jump_table_routines: HashMap<usize, bool>,
+}
+
+/// Traces the code of a rom from the entry points.
+pub fn disassemble_rom(rom: &[u8]) -> RomDisassembly<'_> {
+    let mut tracer = Tracer {
+        disassembly: RomDisassembly {
+            rom,
+            bytes: vec![Byte::Data; rom.len()],
+            labels: BTreeMap::new(),
+            targets: HashMap::new(),
+        },
+        code: Vec::new(),
+        jump_tables: Vec::new(),
+        jump_table_routines: HashMap::new(),
+    };
+
+    for (address, name) in ENTRY_POINTS {
+        if let Some(offset) = tracer.disassembly.offset(0, address) {
+            tracer
+                .disassembly
+                .add_label(offset, LabelKind::EntryPoint(name));
+            tracer.code.push(Location {
+                bank: 0,
+                address,
+                switched_bank: None,
+            });
+        }
+    }
+
+    tracer.trace_code();
+    while let Some(table) = tracer.jump_tables.pop() {
+        tracer.read_jump_table(table);
+    }
+
+    tracer.disassembly
+}
+
+// End of the bank area an address is in, code and tables don't continue past it.
+fn bank_end(address: u16) -> usize {
+    if address < 0x4000 {
+        0x4000
+    } else {
+        0x8000
+    }
+}
+
+impl Tracer<'_> {
+    fn trace_code(&mut self) {
+        while let Some(location) = self.code.pop() {
+            self.trace(location);
+        }
+    }
+
+    // Follows the code from a location until it jumps away or returns.
+    fn trace(&mut self, mut location: Location) {
+        // Last value that is loaded to A, for following the bank switches.
+        let mut a = None;
+        // Address that is loaded to HL and whether an entry is read from it, for the jump tables of JP HL.
+        let mut hl_table = None;
+
+        loop {
+            let Some(offset) = self.disassembly.offset(location.bank, location.address) else {
+                return;
+            };
+
+            let instruction = self.disassembly.instruction(location);
+            let end = offset + instruction.length as usize;
+            let next = location.address as usize + instruction.length as usize;
+
+            // Instructions can't overlap the others or continue past the end of a bank.
+            if instruction.is_undefined()
+                || next > bank_end(location.address)
+                || end > self.disassembly.rom.len()
+                || self.disassembly.bytes[offset..end]
+                    .iter()
+                    .any(|&byte| byte != Byte::Data)
+            {
+                return;
+            }
+
+            self.disassembly.bytes[offset] = Byte::Instruction;
+            self.disassembly.bytes[offset + 1..end].fill(Byte::Operand);
+
+            let operands = instruction.operands.as_slice();
+
+            match (instruction.mnemonic, operands) {
+                ("LD", [Operand::Register(Register::A), Operand::Immediate8(value)]) => {
+                    a = Some(*value)
+                }
+                ("XOR", [Operand::Register(Register::A)]) => a = Some(0),
+                // MBCs select the rom bank with a write to 0x2000-0x3FFF, bank 0 selects bank 1.
+                ("LD", [Operand::Address(0x2000..=0x3FFF), Operand::Register(Register::A)]) => {
+                    location.switched_bank = a.map(|bank| (bank as usize).max(1));
+                }
+                (
+                    "AND" | "OR" | "XOR" | "SUB" | "SBC" | "CPL" | "DAA" | "RLA" | "RRA" | "RLCA"
+                    | "RRCA",
+                    _,
+                )
+                | (_, [Operand::Register(Register::A | Register::AF), ..])
+                | (_, [Operand::Bit(_), Operand::Register(Register::A)]) => a = None,
+                _ => {}
+            }
+
+            match (instruction.mnemonic, operands) {
+                ("LD", [Operand::Register(Register::HL), Operand::Immediate16(address)]) => {
+                    hl_table = Some((*address, false))
+                }
+                ("LD", [_, Operand::Indirect(Register::HL) | Operand::IndirectIncrement]) => {
+                    if let Some((_, read)) = &mut hl_table {
+                        *read = true;
+                    }
+                }
+                _ => {}
+            }
+
+            let conditional = instruction.taken_cycles.is_some();
+
+            match instruction.mnemonic {
+                "JP" | "JR" => {
+                    if let Some(target) = instruction.target() {
+                        self.branch(location, offset, target, LabelKind::Jump);
+                    } else if let Some((table, true)) = hl_table {
+                        // JP HL to an entry of the table.
+                        if let Some(table) = self.disassembly.jump_location(location, table) {
+                            self.jump_tables.push(table);
+                        }
+                    }
+
+                    if !conditional {
+                        return;
+                    }
+                }
+                "CALL" | "RST" => {
+                    let target = instruction.target().unwrap();
+
+                    if let Some(routine) = self.branch(location, offset, target, LabelKind::Call) {
+                        if self.is_jump_table_routine(routine) {
+                            // Routine jumps to an entry of the table after the call, it doesn't return there.
+                            self.jump_tables.push(Location {
+                                address: next as u16,
+                                ..location
+                            });
+                            return;
+                        }
+                    }
+                }
+                "RET" if !conditional => return,
+                "RETI" => return,
+                _ => {}
+            }
+
+            if next >= bank_end(location.address) {
+                return;
+            }
+
+            location.address = next as u16;
+        }
+    }
+
+    // Labels the target of a jump or a call and traces the code there.
+    // Returns the location of the target when it is in the rom.
+    fn branch(
+        &mut self,
+        from: Location,
+        offset: usize,
+        address: u16,
+        kind: LabelKind,
+    ) -> Option<Location> {
+        let target = self.disassembly.jump_location(from, address)?;
+        let target_offset = self.disassembly.offset(target.bank, target.address)?;
+
+        self.disassembly.add_label(target_offset, kind);
+        self.disassembly.targets.insert(offset, target_offset);
+        self.code.push(target);
+
+        Some(target)
+    }
+
+    // Checks if a routine pops its return address to HL and jumps with JP HL, which is how the inline jump tables are read.
+    fn is_jump_table_routine(&mut self, routine: Location) -> bool {
+        let Some(offset) = self.disassembly.offset(routine.bank, routine.address) else {
+            return false;
+        };
+
+        if let Some(&result) = self.jump_table_routines.get(&offset) {
+            return result;
+        }
+
+        let mut location = routine;
+        let mut pops_hl = false;
+        let mut result = false;
+
+        for _ in 0..MAX_JUMP_TABLE_ROUTINE_LENGTH {
+            let instruction = self.disassembly.instruction(location);
+
+            location.address = match (instruction.mnemonic, instruction.operands.as_slice()) {
+                ("POP", [Operand::Register(Register::HL)]) => {
+                    pops_hl = true;
+                    location.address.wrapping_add(instruction.length as u16)
+                }
+                ("JP", [Operand::Register(Register::HL)]) => {
+                    result = pops_hl;
+                    break;
+                }
+                // Vectors of the RST instructions are short, they usually jump to the routine.
+                ("JP" | "JR", [Operand::Target(target)]) => *target,
+                ("JP" | "JR" | "CALL" | "RST" | "RET" | "RETI" | "UNDEFINED", _) => break,
+                _ => location.address.wrapping_add(instruction.length as u16),
+            };
+        }
+
+        self.jump_table_routines.insert(offset, result);
+
+        result
+    }
+
+    // Reads the entries of a jump table and traces the code they point to.
+    fn read_jump_table(&mut self, table: Location) {
+        for index in 0..MAX_JUMP_TABLE_LENGTH {
+            let address = table.address.wrapping_add(index * 2);
+
+            let Some(offset) = self.disassembly.offset(table.bank, address) else {
+                return;
+            };
+
+            // Table ends at the code, the data or the label after it.
+            if address as usize + 2 > bank_end(table.address)
+                || offset + 2 > self.disassembly.rom.len()
+                || self.disassembly.bytes[offset..offset + 2] != [Byte::Data, Byte::Data]
+                || (index != 0 && self.disassembly.labels.contains_key(&offset))
+            {
+                return;
+            }
+
+            let pointer = u16::from_le_bytes([
+                self.disassembly.rom[offset],
+                self.disassembly.rom[offset + 1],
+            ]);
+
+            if pointer < HEADER_END || !self.is_code(table, pointer) {
+                return;
+            }
+
+            self.disassembly.bytes[offset] = Byte::Pointer;
+            self.disassembly.bytes[offset + 1] = Byte::PointerHigh;
+            self.branch(table, offset, pointer, LabelKind::Jump);
+
+            // Code of the entry is traced before the next entry is read, the table might end where it starts.
+            self.trace_code();
+        }
+    }
+
+    // Checks if an address can be the start of an instruction.
+    fn is_code(&self, from: Location, address: u16) -> bool {
+        let Some(location) = self.disassembly.jump_location(from, address) else {
+            return false;
+        };
+        let Some(offset) = self.disassembly.offset(location.bank, location.address) else {
+            return false;
+        };
+
+        match self.disassembly.bytes[offset] {
+            Byte::Instruction => true,
+            Byte::Data => {
+                let instruction = self.disassembly.instruction(location);
+                let end = offset + instruction.length as usize;
+
+                !instruction.is_undefined()
+                    && end <= self.disassembly.rom.len()
+                    && self.disassembly.bytes[offset..end]
+                        .iter()
+                        .all(|&byte| byte == Byte::Data)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl RomDisassembly<'_> {
+    pub fn bank_count(&self) -> usize {
+        self.rom.len().div_ceil(BANK_SIZE)
+    }
+
+    // Rom offset of an address in a bank. Banks other than 0 are only at 0x4000-0x7FFF.
+    fn offset(&self, bank: usize, address: u16) -> Option<usize> {
+        let offset = match address {
+            0x0000..=0x3FFF => address as usize,
+            0x4000..=0x7FFF if bank != 0 => bank * BANK_SIZE + address as usize - BANK_SIZE,
+            _ => return None,
+        };
+
+        (offset < self.rom.len()).then_some(offset)
+    }
+
+    // Bank and address of a rom offset.
+    fn address(offset: usize) -> (usize, u16) {
+        let bank = offset / BANK_SIZE;
+
+        if bank == 0 {
+            (0, offset as u16)
+        } else {
+            (bank, (offset % BANK_SIZE + BANK_SIZE) as u16)
+        }
+    }
+
+    fn instruction(&self, location: Location) -> DisassembledInstruction {
+        disassemble(location.address, |address| {
+            self.offset(location.bank, address)
+                .map_or(0xFF, |offset| self.rom[offset])
+        })
+    }
+
+    // Location that the code at a location jumps to, when its bank is known.
+    fn jump_location(&self, from: Location, address: u16) -> Option<Location> {
+        let bank = match address {
+            0x0000..=0x3FFF => 0,
+            0x4000..=0x7FFF if from.bank != 0 => from.bank,
+            // Roms without banking always have bank 1 at 0x4000.
+            0x4000..=0x7FFF if self.bank_count() <= 2 => 1,
+            0x4000..=0x7FFF => from.switched_bank?,
+            _ => return None,
+        };
+
+        self.offset(bank, address)?;
+
+        Some(Location {
+            bank,
+            address,
+            switched_bank: if from.bank != 0 {
+                Some(from.bank)
+            } else {
+                from.switched_bank
+            },
+        })
+    }
+
+    fn add_label(&mut self, offset: usize, kind: LabelKind) {
+        self.labels
+            .entry(offset)
+            .and_modify(|label| *label = (*label).min(kind))
+            .or_insert(kind);
+    }
+
+    // Labels are written where an instruction, an entry or data starts, not in the middle of them.
+    fn has_label(&self, offset: usize) -> bool {
+        self.labels.contains_key(&offset)
+            && matches!(
+                self.bytes[offset],
+                Byte::Instruction | Byte::Pointer | Byte::Data
+            )
+    }
+
+    fn label_name(&self, offset: usize) -> String {
+        let (bank, address) = Self::address(offset);
+
+        match self.labels[&offset] {
+            LabelKind::EntryPoint(name) => name.to_owned(),
+            LabelKind::Call => format!("Call_{bank:03x}_{address:04x}"),
+            LabelKind::Jump => format!("Jump_{bank:03x}_{address:04x}"),
+        }
+    }
+
+    // Label of the target of an instruction or an entry, if it has one.
+    fn target_label(&self, offset: usize) -> Option<String> {
+        self.targets
+            .get(&offset)
+            .filter(|&&target| self.has_label(target))
+            .map(|&target| self.label_name(target))
+    }
+
+    /// Bytes of the rom that are found to be code, including the jump table entries.
+    pub fn code_size(&self) -> usize {
+        self.bytes
+            .iter()
+            .filter(|&&byte| byte != Byte::Data)
+            .count()
+    }
+
+    /// Writes an RGBDS project that assembles back to the rom.
+    /// There is a source file for every bank, a main source file that includes them and a Makefile.
+    /// # Arguments
+    /// * `directory` - Directory of the project, it is created if it doesn't exist.
+    ///
+    /// * `name` - Name of the main source file and the rom that is built.
+    pub fn write_project(&self, directory: impl AsRef<Path>, name: &str) -> io::Result<()> {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory)?;
+
+        let mut main_source = String::new();
+
+        for bank in 0..self.bank_count() {
+            let file_name = format!("bank_{bank:03x}.asm");
+            fs::write(directory.join(&file_name), self.bank_source(bank))?;
+
+            main_source += &format!("INCLUDE \"{file_name}\"\n");
+        }
+
+        fs::write(directory.join(format!("{name}.asm")), main_source)?;
+        fs::write(directory.join("Makefile"), makefile(name))
+    }
+
+    /// RGBDS source of a bank.
+    pub fn bank_source(&self, bank: usize) -> String {
+        let start = bank * BANK_SIZE;
+        let end = (start + BANK_SIZE).min(self.rom.len());
+
+        let mut source = if bank == 0 {
+            "SECTION \"ROM Bank $000\", ROM0[$0000]\n".to_owned()
+        } else {
+            format!("SECTION \"ROM Bank ${bank:03x}\", ROMX[$4000], BANK[${bank:x}]\n")
+        };
+
+        let mut offset = start;
+
+        while offset < end {
+            if self.has_label(offset) {
+                source += &format!("\n{}:\n", self.label_name(offset));
+            }
+
+            match self.bytes[offset] {
+                Byte::Instruction => {
+                    let (bank, address) = Self::address(offset);
+                    let instruction = self.instruction(Location {
+                        bank,
+                        address,
+                        switched_bank: None,
+                    });
+
+                    source += &format!("    {}\n", self.instruction_source(offset, &instruction));
+                    offset += instruction.length as usize;
+                }
+                Byte::Pointer => {
+                    let label = self.target_label(offset).unwrap_or_else(|| {
+                        let pointer = u16::from_le_bytes([self.rom[offset], self.rom[offset + 1]]);
+                        format!("${pointer:04x}")
+                    });
+
+                    source += &format!("    dw {label}\n");
+                    offset += 2;
+                }
+                _ => {
+                    let data_end = (offset + 1..end)
+                        .find(|&offset| self.bytes[offset] != Byte::Data || self.has_label(offset))
+                        .unwrap_or(end);
+
+                    source += &data_source(&self.rom[offset..data_end]);
+                    offset = data_end;
+                }
+            }
+        }
+
+        source
+    }
+
+    fn instruction_source(&self, offset: usize, instruction: &DisassembledInstruction) -> String {
+        // Instructions that RGBDS may assemble to other bytes are written as data.
+        let ambiguous = match (instruction.mnemonic, instruction.operands.as_slice()) {
+            // STOP is assembled with a NOP after it.
+            ("STOP", _) => true,
+            // Loads from 0xFF00-0xFFFF with a 16 bit address can be optimized to LDH.
+            ("LD", [Operand::Address(0xFF00..), _] | [_, Operand::Address(0xFF00..)]) => true,
+            _ => false,
+        };
+
+        if ambiguous {
+            let bytes: Vec<String> = instruction
+                .bytes
+                .iter()
+                .map(|byte| format!("${byte:02x}"))
+                .collect();
+
+            return format!("db {} ; {instruction}", bytes.join(", "));
+        }
+
+        let mut source = String::new();
+        let _ = instruction.write_rgbds(&mut source, self.target_label(offset).as_deref());
+
+        source
+    }
+}
+
+// Writes data with `db`, and the long runs of the same byte with `ds`.
+fn data_source(data: &[u8]) -> String {
+    let mut source = String::new();
+    let mut line = Vec::new();
+
+    let write_line = |source: &mut String, line: &mut Vec<String>| {
+        if !line.is_empty() {
+            *source += &format!("    db {}\n", line.join(", "));
+            line.clear();
+        }
+    };
+
+    let mut index = 0;
+
+    while index < data.len() {
+        let value = data[index];
+        let run = data[index..]
+            .iter()
+            .take_while(|&&byte| byte == value)
+            .count();
+
+        if run >= MIN_FILL_LENGTH {
+            write_line(&mut source, &mut line);
+            source += &format!("    ds {run}, ${value:02x}\n");
+            index += run;
+        } else {
+            line.push(format!("${value:02x}"));
+            if line.len() == DATA_BYTES_PER_LINE {
+                write_line(&mut source, &mut line);
+            }
+            index += 1;
+        }
+    }
+
+    write_line(&mut source, &mut line);
+
+    source
+}
+
+fn makefile(name: &str) -> String {
+    format!(
+        "# Builds the rom with RGBDS 0.6 or newer.\n\
+        {name}.gb: {name}.o\n\
+        \trgblink -n {name}.sym -o $@ $<\n\
+        \n\
+        {name}.o: {name}.asm $(wildcard bank_*.asm)\n\
+        \trgbasm -o $@ $<\n\
+        \n\
+        clean:\n\
+        \trm -f {name}.gb {name}.o {name}.sym\n\
+        \n\
+        .PHONY: clean\n"
+    )
+}
@@ -1,13 +1,14 @@
 #![allow(dead_code, unused_variables)]
-use crate::memory_map::{MemoryMap, OamCorruption};
+use crate::bus::Bus;
+use crate::memory_map::OamCorruption;
 use crate::Cpu;
 
 /// INC BC - 0x03 <br>
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn inc_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.bc(), OamCorruption::Write);
+pub fn inc_bc(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.bc(), OamCorruption::Write);
     cpu.registers.set_bc(cpu.registers.bc().wrapping_add(1));
     8
 }
@@ -16,7 +17,7 @@ pub fn inc_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn inc_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.inc(cpu.registers.b);
     4
 }
@@ -25,7 +26,7 @@ pub fn inc_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn dec_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.dec(cpu.registers.b);
     4
 }
@@ -34,7 +35,7 @@ pub fn dec_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - 0 H C
-pub fn add_hl_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_hl_bc(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add_u16(cpu.registers.bc());
     8
 }
@@ -43,8 +44,8 @@ pub fn add_hl_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn dec_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.bc(), OamCorruption::Write);
+pub fn dec_bc(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.bc(), OamCorruption::Write);
     cpu.registers.set_bc(cpu.registers.bc().wrapping_sub(1));
     8
 }
@@ -53,7 +54,7 @@ pub fn dec_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn inc_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.inc(cpu.registers.c);
     4
 }
@@ -62,7 +63,7 @@ pub fn inc_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn dec_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.dec(cpu.registers.c);
     4
 }
@@ -71,8 +72,8 @@ pub fn dec_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn inc_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.de(), OamCorruption::Write);
+pub fn inc_de(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.de(), OamCorruption::Write);
     cpu.registers.set_de(cpu.registers.de().wrapping_add(1));
     8
 }
@@ -81,7 +82,7 @@ pub fn inc_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn inc_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.inc(cpu.registers.d);
     4
 }
@@ -90,7 +91,7 @@ pub fn inc_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn dec_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.dec(cpu.registers.d);
     4
 }
@@ -99,7 +100,7 @@ pub fn dec_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - 0 H C
-pub fn add_hl_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_hl_de(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add_u16(cpu.registers.de());
     8
 }
@@ -108,8 +109,8 @@ pub fn add_hl_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn dec_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.de(), OamCorruption::Write);
+pub fn dec_de(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.de(), OamCorruption::Write);
     cpu.registers.set_de(cpu.registers.de().wrapping_sub(1));
     8
 }
@@ -118,7 +119,7 @@ pub fn dec_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn inc_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.inc(cpu.registers.e);
     4
 }
@@ -127,7 +128,7 @@ pub fn inc_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn dec_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.dec(cpu.registers.e);
     4
 }
@@ -136,8 +137,8 @@ pub fn dec_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn inc_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
+pub fn inc_hl(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
     cpu.registers.set_hl(cpu.registers.hl().wrapping_add(1));
     8
 }
@@ -146,7 +147,7 @@ pub fn inc_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn inc_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.inc(cpu.registers.h);
     4
 }
@@ -155,7 +156,7 @@ pub fn inc_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn dec_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.dec(cpu.registers.h);
     4
 }
@@ -164,7 +165,7 @@ pub fn dec_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z - 0 C
-pub fn daa(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn daa(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     /*
         DAA is intended to be run immediately after an addition or subtraction operation,
         where the operands were BCD encoded.
@@ -204,7 +205,7 @@ pub fn daa(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - 0 H C
-pub fn add_hl_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_hl_hl(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add_u16(cpu.registers.hl());
     8
 }
@@ -213,8 +214,8 @@ pub fn add_hl_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn dec_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
+pub fn dec_hl(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
     cpu.registers.set_hl(cpu.registers.hl().wrapping_sub(1));
     8
 }
@@ -223,7 +224,7 @@ pub fn dec_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn inc_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.inc(cpu.registers.l);
     4
 }
@@ -232,7 +233,7 @@ pub fn inc_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn dec_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.dec(cpu.registers.l);
     4
 }
@@ -241,7 +242,7 @@ pub fn dec_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - 1 1 -
-pub fn cpl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cpl(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // Complement A register. (Flip all bits.)
     cpu.registers.a = !cpu.registers.a;
     cpu.registers
@@ -253,8 +254,8 @@ pub fn cpl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn inc_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.sp, OamCorruption::Write);
+pub fn inc_sp(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.sp, OamCorruption::Write);
     cpu.sp = cpu.sp.wrapping_add(1);
     8
 }
@@ -263,12 +264,10 @@ pub fn inc_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.inc(memory_map.cpu_get(cpu.registers.hl())),
-    );
+pub fn inc_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
+    let value = cpu.inc(bus.read(cpu.registers.hl()));
+    bus.write(cpu.registers.hl(), value);
     12
 }
 
@@ -276,12 +275,10 @@ pub fn inc_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.dec(memory_map.cpu_get(cpu.registers.hl())),
-    );
+pub fn dec_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::Write);
+    let value = cpu.dec(bus.read(cpu.registers.hl()));
+    bus.write(cpu.registers.hl(), value);
     12
 }
 
@@ -289,7 +286,7 @@ pub fn dec_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - 0 0 1
-pub fn scf(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn scf(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // Set Carry flag.
     cpu.registers.set_flags(cpu.registers.get_z(), 0, 0, 1);
     4
@@ -299,7 +296,7 @@ pub fn scf(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - 0 H C
-pub fn add_hl_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_hl_sp(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add_u16(cpu.sp);
     8
 }
@@ -308,8 +305,8 @@ pub fn add_hl_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn dec_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.try_corrupt_oam(cpu.sp, OamCorruption::Write);
+pub fn dec_sp(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.try_corrupt_oam(cpu.sp, OamCorruption::Write);
     cpu.sp = cpu.sp.wrapping_sub(1);
     8
 }
@@ -318,7 +315,7 @@ pub fn dec_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H -
-pub fn inc_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn inc_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.inc(cpu.registers.a);
     4
 }
@@ -327,7 +324,7 @@ pub fn inc_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H -
-pub fn dec_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn dec_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.dec(cpu.registers.a);
     4
 }
@@ -336,7 +333,7 @@ pub fn dec_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - 0 0 C
-pub fn ccf(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ccf(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // Complement carry flag.
     cpu.registers.set_flags(
         cpu.registers.get_z(),
@@ -351,7 +348,7 @@ pub fn ccf(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_a_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add(cpu.registers.b);
     4
 }
@@ -360,7 +357,7 @@ pub fn add_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_a_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add(cpu.registers.c);
     4
 }
@@ -369,7 +366,7 @@ pub fn add_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_a_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add(cpu.registers.d);
     4
 }
@@ -378,7 +375,7 @@ pub fn add_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_a_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add(cpu.registers.e);
     4
 }
@@ -387,7 +384,7 @@ pub fn add_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_a_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add(cpu.registers.h);
     4
 }
@@ -396,7 +393,7 @@ pub fn add_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_a_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add(cpu.registers.l);
     4
 }
@@ -405,8 +402,8 @@ pub fn add_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.add(memory_map.cpu_get(cpu.registers.hl()));
+pub fn add_a_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.add(bus.read(cpu.registers.hl()));
     8
 }
 
@@ -414,7 +411,7 @@ pub fn add_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_a_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.add(cpu.registers.a);
     4
 }
@@ -423,7 +420,7 @@ pub fn add_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn adc_a_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.adc(cpu.registers.b);
     4
 }
@@ -432,7 +429,7 @@ pub fn adc_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn adc_a_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.adc(cpu.registers.c);
     4
 }
@@ -441,7 +438,7 @@ pub fn adc_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn adc_a_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.adc(cpu.registers.d);
     4
 }
@@ -450,7 +447,7 @@ pub fn adc_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn adc_a_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.adc(cpu.registers.e);
     4
 }
@@ -459,7 +456,7 @@ pub fn adc_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn adc_a_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.adc(cpu.registers.h);
     4
 }
@@ -468,7 +465,7 @@ pub fn adc_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn adc_a_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.adc(cpu.registers.l);
     4
 }
@@ -477,8 +474,8 @@ pub fn adc_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.adc(memory_map.cpu_get(cpu.registers.hl()));
+pub fn adc_a_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.adc(bus.read(cpu.registers.hl()));
     8
 }
 
@@ -486,7 +483,7 @@ pub fn adc_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn adc_a_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.adc(cpu.registers.a);
     4
 }
@@ -495,7 +492,7 @@ pub fn adc_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sub_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sub(cpu.registers.b);
     4
 }
@@ -504,7 +501,7 @@ pub fn sub_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sub_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sub(cpu.registers.c);
     4
 }
@@ -513,7 +510,7 @@ pub fn sub_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sub_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sub(cpu.registers.d);
     4
 }
@@ -522,7 +519,7 @@ pub fn sub_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sub_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sub(cpu.registers.e);
     4
 }
@@ -531,7 +528,7 @@ pub fn sub_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sub_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sub(cpu.registers.h);
     4
 }
@@ -540,7 +537,7 @@ pub fn sub_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sub_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sub(cpu.registers.l);
     4
 }
@@ -549,8 +546,8 @@ pub fn sub_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.sub(memory_map.cpu_get(cpu.registers.hl()));
+pub fn sub_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.sub(bus.read(cpu.registers.hl()));
     8
 }
 
@@ -558,7 +555,7 @@ pub fn sub_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sub_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sub(cpu.registers.a);
     4
 }
@@ -567,7 +564,7 @@ pub fn sub_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sbc_a_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sbc(cpu.registers.b);
     4
 }
@@ -576,7 +573,7 @@ pub fn sbc_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sbc_a_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sbc(cpu.registers.c);
     4
 }
@@ -585,7 +582,7 @@ pub fn sbc_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sbc_a_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sbc(cpu.registers.d);
     4
 }
@@ -594,7 +591,7 @@ pub fn sbc_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sbc_a_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sbc(cpu.registers.e);
     4
 }
@@ -603,7 +600,7 @@ pub fn sbc_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sbc_a_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sbc(cpu.registers.h);
     4
 }
@@ -612,7 +609,7 @@ pub fn sbc_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sbc_a_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sbc(cpu.registers.l);
     4
 }
@@ -621,8 +618,8 @@ pub fn sbc_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.sbc(memory_map.cpu_get(cpu.registers.hl()));
+pub fn sbc_a_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.sbc(bus.read(cpu.registers.hl()));
     8
 }
 
@@ -630,7 +627,7 @@ pub fn sbc_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sbc_a_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sbc(cpu.registers.a);
     4
 }
@@ -639,7 +636,7 @@ pub fn sbc_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn and_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.and(cpu.registers.b);
     4
 }
@@ -648,7 +645,7 @@ pub fn and_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn and_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.and(cpu.registers.c);
     4
 }
@@ -657,7 +654,7 @@ pub fn and_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn and_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.and(cpu.registers.d);
     4
 }
@@ -666,7 +663,7 @@ pub fn and_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn and_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.and(cpu.registers.e);
     4
 }
@@ -675,7 +672,7 @@ pub fn and_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn and_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.and(cpu.registers.h);
     4
 }
@@ -684,7 +681,7 @@ pub fn and_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn and_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.and(cpu.registers.l);
     4
 }
@@ -693,8 +690,8 @@ pub fn and_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.and(memory_map.cpu_get(cpu.registers.hl()));
+pub fn and_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.and(bus.read(cpu.registers.hl()));
     8
 }
 
@@ -702,7 +699,7 @@ pub fn and_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn and_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.and(cpu.registers.a);
     4
 }
@@ -711,7 +708,7 @@ pub fn and_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn xor_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.xor(cpu.registers.b);
     4
 }
@@ -720,7 +717,7 @@ pub fn xor_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn xor_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.xor(cpu.registers.c);
     4
 }
@@ -729,7 +726,7 @@ pub fn xor_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn xor_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.xor(cpu.registers.d);
     4
 }
@@ -738,7 +735,7 @@ pub fn xor_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn xor_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.xor(cpu.registers.e);
     4
 }
@@ -747,7 +744,7 @@ pub fn xor_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn xor_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.xor(cpu.registers.h);
     4
 }
@@ -756,7 +753,7 @@ pub fn xor_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn xor_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.xor(cpu.registers.l);
     4
 }
@@ -765,8 +762,8 @@ pub fn xor_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.xor(memory_map.cpu_get(cpu.registers.hl()));
+pub fn xor_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.xor(bus.read(cpu.registers.hl()));
     8
 }
 
@@ -774,7 +771,7 @@ pub fn xor_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn xor_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.xor(cpu.registers.a);
     4
 }
@@ -783,7 +780,7 @@ pub fn xor_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn or_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.or(cpu.registers.b);
     4
 }
@@ -792,7 +789,7 @@ pub fn or_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn or_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.or(cpu.registers.c);
     4
 }
@@ -801,7 +798,7 @@ pub fn or_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn or_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.or(cpu.registers.d);
     4
 }
@@ -810,7 +807,7 @@ pub fn or_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn or_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.or(cpu.registers.e);
     4
 }
@@ -819,7 +816,7 @@ pub fn or_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn or_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.or(cpu.registers.h);
     4
 }
@@ -828,7 +825,7 @@ pub fn or_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn or_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.or(cpu.registers.l);
     4
 }
@@ -837,8 +834,8 @@ pub fn or_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.or(memory_map.cpu_get(cpu.registers.hl()));
+pub fn or_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.or(bus.read(cpu.registers.hl()));
     8
 }
 
@@ -846,7 +843,7 @@ pub fn or_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn or_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.or(cpu.registers.a);
     4
 }
@@ -855,7 +852,7 @@ pub fn or_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
     cpu.sub(cpu.registers.b);
     cpu.registers.a = old_a;
@@ -866,7 +863,7 @@ pub fn cp_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
     cpu.sub(cpu.registers.c);
     cpu.registers.a = old_a;
@@ -877,7 +874,7 @@ pub fn cp_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
     cpu.sub(cpu.registers.d);
     cpu.registers.a = old_a;
@@ -888,7 +885,7 @@ pub fn cp_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
     cpu.sub(cpu.registers.e);
     cpu.registers.a = old_a;
@@ -899,7 +896,7 @@ pub fn cp_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
     cpu.sub(cpu.registers.h);
     cpu.registers.a = old_a;
@@ -910,7 +907,7 @@ pub fn cp_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
     cpu.sub(cpu.registers.l);
     cpu.registers.a = old_a;
@@ -921,9 +918,9 @@ pub fn cp_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
-    cpu.sub(memory_map.cpu_get(cpu.registers.hl()));
+    cpu.sub(bus.read(cpu.registers.hl()));
     cpu.registers.a = old_a;
     8
 }
@@ -932,7 +929,7 @@ pub fn cp_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
     cpu.sub(cpu.registers.a);
     cpu.registers.a = old_a;
@@ -943,8 +940,8 @@ pub fn cp_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 H C
-pub fn add_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.add(cpu.get_immediate_u8(memory_map));
+pub fn add_a_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.add(cpu.get_immediate_u8(bus));
     8
 }
 
@@ -952,8 +949,8 @@ pub fn add_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 H C
-pub fn adc_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.adc(cpu.get_immediate_u8(memory_map));
+pub fn adc_a_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.adc(cpu.get_immediate_u8(bus));
     8
 }
 
@@ -961,8 +958,8 @@ pub fn adc_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 1 H C
-pub fn sub_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.sub(cpu.get_immediate_u8(memory_map));
+pub fn sub_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.sub(cpu.get_immediate_u8(bus));
     8
 }
 
@@ -970,8 +967,8 @@ pub fn sub_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 1 H C
-pub fn sbc_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.sbc(cpu.get_immediate_u8(memory_map));
+pub fn sbc_a_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.sbc(cpu.get_immediate_u8(bus));
     8
 }
 
@@ -979,8 +976,8 @@ pub fn sbc_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 0
-pub fn and_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.and(cpu.get_immediate_u8(memory_map));
+pub fn and_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.and(cpu.get_immediate_u8(bus));
     8
 }
 
@@ -988,10 +985,10 @@ pub fn and_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: 0 0 H C
-pub fn add_sp_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn add_sp_r8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // https://stackoverflow.com/questions/57958631/game-boy-half-carry-flag-and-16-bit-instructions-especially-opcode-0xe8
 
-    let val = cpu.get_immediate_u8(memory_map);
+    let val = cpu.get_immediate_u8(bus);
 
     let half_carry = ((cpu.sp & 0xF) + ((val as u16) & 0xF)) > 0xF;
     let carry = ((cpu.sp & 0xFF) + ((val as u16) & 0xFF)) > 0xFF;
@@ -1007,8 +1004,8 @@ pub fn add_sp_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn xor_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.xor(cpu.get_immediate_u8(memory_map));
+pub fn xor_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.xor(cpu.get_immediate_u8(bus));
     8
 }
 
@@ -1016,8 +1013,8 @@ pub fn xor_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn or_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.or(cpu.get_immediate_u8(memory_map));
+pub fn or_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.or(cpu.get_immediate_u8(bus));
     8
 }
 
@@ -1025,9 +1022,9 @@ pub fn or_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 1 H C
-pub fn cp_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn cp_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     let old_a = cpu.registers.a;
-    cpu.sub(cpu.get_immediate_u8(memory_map));
+    cpu.sub(cpu.get_immediate_u8(bus));
     cpu.registers.a = old_a;
     8
 }
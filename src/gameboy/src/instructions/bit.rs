@@ -1,12 +1,12 @@
 #![allow(dead_code, unused_variables)]
-use crate::memory_map::MemoryMap;
+use crate::bus::Bus;
 use crate::Cpu;
 
 /// RLCA - 0x07 <br>
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: 0 0 0 C
-pub fn rlca(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlca(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.circular_shift_left(cpu.registers.a, 1);
     // Reset the z flag because above instruction sets the z flag based on the result.
     cpu.registers.set_flags(0, 0, 0, cpu.registers.get_cy());
@@ -17,7 +17,7 @@ pub fn rlca(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: 0 0 0 C
-pub fn rrca(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrca(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.circular_shift_right(cpu.registers.a, 1);
     // Reset the z flag because above instruction sets the z flag based on the result.
     cpu.registers.set_flags(0, 0, 0, cpu.registers.get_cy());
@@ -28,7 +28,7 @@ pub fn rrca(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: 0 0 0 C
-pub fn rla(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rla(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.rotate_left_cy(cpu.registers.a, 1);
     // Reset the z flag because above instruction sets the z flag based on the result.
     cpu.registers.set_flags(0, 0, 0, cpu.registers.get_cy());
@@ -39,7 +39,7 @@ pub fn rla(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: 0 0 0 C
-pub fn rra(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rra(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.rotate_right_cy(cpu.registers.a, 1);
     // Reset the z flag because above instruction sets the z flag based on the result.
     cpu.registers.set_flags(0, 0, 0, cpu.registers.get_cy());
@@ -50,7 +50,7 @@ pub fn rra(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlc_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.circular_shift_left(cpu.registers.b, 1);
     8
 }
@@ -59,7 +59,7 @@ pub fn rlc_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlc_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.circular_shift_left(cpu.registers.c, 1);
     8
 }
@@ -68,7 +68,7 @@ pub fn rlc_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlc_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.circular_shift_left(cpu.registers.d, 1);
     8
 }
@@ -77,7 +77,7 @@ pub fn rlc_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlc_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.circular_shift_left(cpu.registers.e, 1);
     8
 }
@@ -86,7 +86,7 @@ pub fn rlc_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlc_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.circular_shift_left(cpu.registers.h, 1);
     8
 }
@@ -95,7 +95,7 @@ pub fn rlc_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlc_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.circular_shift_left(cpu.registers.l, 1);
     8
 }
@@ -104,11 +104,9 @@ pub fn rlc_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.circular_shift_left(memory_map.cpu_get(cpu.registers.hl()), 1),
-    );
+pub fn rlc_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.circular_shift_left(bus.read(cpu.registers.hl()), 1);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -116,7 +114,7 @@ pub fn rlc_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rlc_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rlc_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.circular_shift_left(cpu.registers.a, 1);
     8
 }
@@ -125,7 +123,7 @@ pub fn rlc_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrc_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.circular_shift_right(cpu.registers.b, 1);
     8
 }
@@ -134,7 +132,7 @@ pub fn rrc_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrc_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.circular_shift_right(cpu.registers.c, 1);
     8
 }
@@ -143,7 +141,7 @@ pub fn rrc_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrc_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.circular_shift_right(cpu.registers.d, 1);
     8
 }
@@ -152,7 +150,7 @@ pub fn rrc_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrc_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.circular_shift_right(cpu.registers.e, 1);
     8
 }
@@ -161,7 +159,7 @@ pub fn rrc_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrc_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.circular_shift_right(cpu.registers.h, 1);
     8
 }
@@ -170,7 +168,7 @@ pub fn rrc_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrc_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.circular_shift_right(cpu.registers.l, 1);
     8
 }
@@ -179,11 +177,9 @@ pub fn rrc_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.circular_shift_right(memory_map.cpu_get(cpu.registers.hl()), 1),
-    );
+pub fn rrc_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.circular_shift_right(bus.read(cpu.registers.hl()), 1);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -191,7 +187,7 @@ pub fn rrc_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rrc_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rrc_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.circular_shift_right(cpu.registers.a, 1);
     8
 }
@@ -200,7 +196,7 @@ pub fn rrc_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rl_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.rotate_left_cy(cpu.registers.b, 1);
     8
 }
@@ -209,7 +205,7 @@ pub fn rl_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rl_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.rotate_left_cy(cpu.registers.c, 1);
     8
 }
@@ -218,7 +214,7 @@ pub fn rl_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rl_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.rotate_left_cy(cpu.registers.d, 1);
     8
 }
@@ -227,7 +223,7 @@ pub fn rl_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rl_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.rotate_left_cy(cpu.registers.e, 1);
     8
 }
@@ -236,7 +232,7 @@ pub fn rl_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rl_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.rotate_left_cy(cpu.registers.h, 1);
     8
 }
@@ -245,7 +241,7 @@ pub fn rl_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rl_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.rotate_left_cy(cpu.registers.l, 1);
     8
 }
@@ -254,11 +250,9 @@ pub fn rl_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.rotate_left_cy(memory_map.cpu_get(cpu.registers.hl()), 1),
-    );
+pub fn rl_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.rotate_left_cy(bus.read(cpu.registers.hl()), 1);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -266,7 +260,7 @@ pub fn rl_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rl_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rl_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.rotate_left_cy(cpu.registers.a, 1);
     8
 }
@@ -275,7 +269,7 @@ pub fn rl_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rr_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.rotate_right_cy(cpu.registers.b, 1);
     8
 }
@@ -284,7 +278,7 @@ pub fn rr_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rr_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.rotate_right_cy(cpu.registers.c, 1);
     8
 }
@@ -293,7 +287,7 @@ pub fn rr_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rr_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.rotate_right_cy(cpu.registers.d, 1);
     8
 }
@@ -302,7 +296,7 @@ pub fn rr_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rr_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.rotate_right_cy(cpu.registers.e, 1);
     8
 }
@@ -311,7 +305,7 @@ pub fn rr_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rr_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.rotate_right_cy(cpu.registers.h, 1);
     8
 }
@@ -320,7 +314,7 @@ pub fn rr_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rr_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.rotate_right_cy(cpu.registers.l, 1);
     8
 }
@@ -329,11 +323,9 @@ pub fn rr_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.rotate_right_cy(memory_map.cpu_get(cpu.registers.hl()), 1),
-    );
+pub fn rr_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.rotate_right_cy(bus.read(cpu.registers.hl()), 1);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -341,7 +333,7 @@ pub fn rr_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn rr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn rr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.rotate_right_cy(cpu.registers.a, 1);
     8
 }
@@ -350,7 +342,7 @@ pub fn rr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sla_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.shift_left_arithmetic(cpu.registers.b);
     8
 }
@@ -359,7 +351,7 @@ pub fn sla_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sla_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.shift_left_arithmetic(cpu.registers.c);
     8
 }
@@ -368,7 +360,7 @@ pub fn sla_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sla_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.shift_left_arithmetic(cpu.registers.d);
     8
 }
@@ -377,7 +369,7 @@ pub fn sla_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sla_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.shift_left_arithmetic(cpu.registers.e);
     8
 }
@@ -386,7 +378,7 @@ pub fn sla_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sla_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.shift_left_arithmetic(cpu.registers.h);
     8
 }
@@ -395,7 +387,7 @@ pub fn sla_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sla_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.shift_left_arithmetic(cpu.registers.l);
     8
 }
@@ -404,11 +396,9 @@ pub fn sla_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.shift_left_arithmetic(memory_map.cpu_get(cpu.registers.hl())),
-    );
+pub fn sla_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.shift_left_arithmetic(bus.read(cpu.registers.hl()));
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -416,7 +406,7 @@ pub fn sla_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn sla_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn sla_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.shift_left_arithmetic(cpu.registers.a);
     8
 }
@@ -424,8 +414,8 @@ pub fn sla_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 /// SRA B - 0x28 <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+///  Flags affected: Z 0 0 C
+pub fn sra_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.shift_right_arithmetic(cpu.registers.b);
     8
 }
@@ -433,8 +423,8 @@ pub fn sra_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 /// SRA C - 0x29 <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+///  Flags affected: Z 0 0 C
+pub fn sra_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.shift_right_arithmetic(cpu.registers.c);
     8
 }
@@ -442,8 +432,8 @@ pub fn sra_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 /// SRA D - 0x2A <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+///  Flags affected: Z 0 0 C
+pub fn sra_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.shift_right_arithmetic(cpu.registers.d);
     8
 }
@@ -451,8 +441,8 @@ pub fn sra_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 /// SRA E - 0x2B <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+///  Flags affected: Z 0 0 C
+pub fn sra_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.shift_right_arithmetic(cpu.registers.e);
     8
 }
@@ -460,8 +450,8 @@ pub fn sra_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 /// SRA H - 0x2C <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+///  Flags affected: Z 0 0 C
+pub fn sra_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.shift_right_arithmetic(cpu.registers.h);
     8
 }
@@ -469,8 +459,8 @@ pub fn sra_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 /// SRA L - 0x2D <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+///  Flags affected: Z 0 0 C
+pub fn sra_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.shift_right_arithmetic(cpu.registers.l);
     8
 }
@@ -478,20 +468,18 @@ pub fn sra_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 /// SRA (HL) - 0x2E <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.shift_right_arithmetic(memory_map.cpu_get(cpu.registers.hl())),
-    );
+///  Flags affected: Z 0 0 C
+pub fn sra_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.shift_right_arithmetic(bus.read(cpu.registers.hl()));
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
 /// SRA A - 0x2F <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
-///  Flags affected: Z 0 0 0
-pub fn sra_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+///  Flags affected: Z 0 0 C
+pub fn sra_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.shift_right_arithmetic(cpu.registers.a);
     8
 }
@@ -500,7 +488,7 @@ pub fn sra_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn swap_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.swap(cpu.registers.b);
     8
 }
@@ -509,7 +497,7 @@ pub fn swap_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn swap_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.swap(cpu.registers.c);
     8
 }
@@ -518,7 +506,7 @@ pub fn swap_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn swap_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.swap(cpu.registers.d);
     8
 }
@@ -527,7 +515,7 @@ pub fn swap_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn swap_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.swap(cpu.registers.e);
     8
 }
@@ -536,7 +524,7 @@ pub fn swap_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn swap_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.swap(cpu.registers.h);
     8
 }
@@ -545,7 +533,7 @@ pub fn swap_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn swap_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.swap(cpu.registers.l);
     8
 }
@@ -554,11 +542,9 @@ pub fn swap_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.swap(memory_map.cpu_get(cpu.registers.hl())),
-    );
+pub fn swap_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.swap(bus.read(cpu.registers.hl()));
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -566,7 +552,7 @@ pub fn swap_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 0
-pub fn swap_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn swap_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.swap(cpu.registers.a);
     8
 }
@@ -575,7 +561,7 @@ pub fn swap_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn srl_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.shift_right_logical(cpu.registers.b);
     8
 }
@@ -584,7 +570,7 @@ pub fn srl_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn srl_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.shift_right_logical(cpu.registers.c);
     8
 }
@@ -593,7 +579,7 @@ pub fn srl_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn srl_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.shift_right_logical(cpu.registers.d);
     8
 }
@@ -602,7 +588,7 @@ pub fn srl_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn srl_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.shift_right_logical(cpu.registers.e);
     8
 }
@@ -611,7 +597,7 @@ pub fn srl_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn srl_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.shift_right_logical(cpu.registers.h);
     8
 }
@@ -620,7 +606,7 @@ pub fn srl_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn srl_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.shift_right_logical(cpu.registers.l);
     8
 }
@@ -629,11 +615,9 @@ pub fn srl_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.shift_right_logical(memory_map.cpu_get(cpu.registers.hl())),
-    );
+pub fn srl_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.shift_right_logical(bus.read(cpu.registers.hl()));
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -641,7 +625,7 @@ pub fn srl_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 0 C
-pub fn srl_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn srl_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.shift_right_logical(cpu.registers.a);
     8
 }
@@ -650,7 +634,7 @@ pub fn srl_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_0_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 0);
     8
 }
@@ -659,7 +643,7 @@ pub fn bit_0_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_0_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 0);
     8
 }
@@ -668,7 +652,7 @@ pub fn bit_0_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_0_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 0);
     8
 }
@@ -677,7 +661,7 @@ pub fn bit_0_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_0_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 0);
     8
 }
@@ -686,7 +670,7 @@ pub fn bit_0_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_0_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 0);
     8
 }
@@ -695,7 +679,7 @@ pub fn bit_0_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_0_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 0);
     8
 }
@@ -704,8 +688,8 @@ pub fn bit_0_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 0);
+pub fn bit_0_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 0);
     12
 }
 
@@ -713,7 +697,7 @@ pub fn bit_0_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_0_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_0_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 0);
     8
 }
@@ -722,7 +706,7 @@ pub fn bit_0_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_1_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 1);
     8
 }
@@ -731,7 +715,7 @@ pub fn bit_1_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_1_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 1);
     8
 }
@@ -740,7 +724,7 @@ pub fn bit_1_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_1_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 1);
     8
 }
@@ -749,7 +733,7 @@ pub fn bit_1_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_1_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 1);
     8
 }
@@ -758,7 +742,7 @@ pub fn bit_1_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_1_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 1);
     8
 }
@@ -767,7 +751,7 @@ pub fn bit_1_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_1_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 1);
     8
 }
@@ -776,8 +760,8 @@ pub fn bit_1_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 1);
+pub fn bit_1_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 1);
     12
 }
 
@@ -785,7 +769,7 @@ pub fn bit_1_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_1_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_1_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 1);
     8
 }
@@ -794,7 +778,7 @@ pub fn bit_1_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_2_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 2);
     8
 }
@@ -803,7 +787,7 @@ pub fn bit_2_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_2_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 2);
     8
 }
@@ -812,7 +796,7 @@ pub fn bit_2_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_2_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 2);
     8
 }
@@ -821,7 +805,7 @@ pub fn bit_2_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_2_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 2);
     8
 }
@@ -830,7 +814,7 @@ pub fn bit_2_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_2_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 2);
     8
 }
@@ -839,7 +823,7 @@ pub fn bit_2_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_2_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 2);
     8
 }
@@ -848,8 +832,8 @@ pub fn bit_2_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 2);
+pub fn bit_2_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 2);
     12
 }
 
@@ -857,7 +841,7 @@ pub fn bit_2_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_2_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_2_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 2);
     8
 }
@@ -866,7 +850,7 @@ pub fn bit_2_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_3_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 3);
     8
 }
@@ -875,7 +859,7 @@ pub fn bit_3_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_3_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 3);
     8
 }
@@ -884,7 +868,7 @@ pub fn bit_3_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_3_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 3);
     8
 }
@@ -893,7 +877,7 @@ pub fn bit_3_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_3_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 3);
     8
 }
@@ -902,7 +886,7 @@ pub fn bit_3_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_3_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 3);
     8
 }
@@ -911,7 +895,7 @@ pub fn bit_3_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_3_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 3);
     8
 }
@@ -920,8 +904,8 @@ pub fn bit_3_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 3);
+pub fn bit_3_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 3);
     12
 }
 
@@ -929,7 +913,7 @@ pub fn bit_3_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_3_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_3_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 3);
     8
 }
@@ -938,7 +922,7 @@ pub fn bit_3_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_4_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 4);
     8
 }
@@ -947,7 +931,7 @@ pub fn bit_4_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_4_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 4);
     8
 }
@@ -956,7 +940,7 @@ pub fn bit_4_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_4_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 4);
     8
 }
@@ -965,7 +949,7 @@ pub fn bit_4_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_4_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 4);
     8
 }
@@ -974,7 +958,7 @@ pub fn bit_4_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_4_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 4);
     8
 }
@@ -983,7 +967,7 @@ pub fn bit_4_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_4_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 4);
     8
 }
@@ -992,8 +976,8 @@ pub fn bit_4_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 4);
+pub fn bit_4_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 4);
     12
 }
 
@@ -1001,7 +985,7 @@ pub fn bit_4_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_4_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_4_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 4);
     8
 }
@@ -1010,7 +994,7 @@ pub fn bit_4_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_5_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 5);
     8
 }
@@ -1019,7 +1003,7 @@ pub fn bit_5_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_5_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 5);
     8
 }
@@ -1028,7 +1012,7 @@ pub fn bit_5_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_5_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 5);
     8
 }
@@ -1037,7 +1021,7 @@ pub fn bit_5_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_5_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 5);
     8
 }
@@ -1046,7 +1030,7 @@ pub fn bit_5_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_5_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 5);
     8
 }
@@ -1055,7 +1039,7 @@ pub fn bit_5_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_5_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 5);
     8
 }
@@ -1064,8 +1048,8 @@ pub fn bit_5_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 5);
+pub fn bit_5_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 5);
     12
 }
 
@@ -1073,7 +1057,7 @@ pub fn bit_5_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_5_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_5_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 5);
     8
 }
@@ -1082,7 +1066,7 @@ pub fn bit_5_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_6_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 6);
     8
 }
@@ -1091,7 +1075,7 @@ pub fn bit_6_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_6_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 6);
     8
 }
@@ -1100,7 +1084,7 @@ pub fn bit_6_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_6_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 6);
     8
 }
@@ -1109,7 +1093,7 @@ pub fn bit_6_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_6_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 6);
     8
 }
@@ -1118,7 +1102,7 @@ pub fn bit_6_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_6_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 6);
     8
 }
@@ -1127,7 +1111,7 @@ pub fn bit_6_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_6_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 6);
     8
 }
@@ -1136,8 +1120,8 @@ pub fn bit_6_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 6);
+pub fn bit_6_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 6);
     12
 }
 
@@ -1145,7 +1129,7 @@ pub fn bit_6_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_6_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_6_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 6);
     8
 }
@@ -1154,7 +1138,7 @@ pub fn bit_6_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_7_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.b, 7);
     8
 }
@@ -1163,7 +1147,7 @@ pub fn bit_7_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_7_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.c, 7);
     8
 }
@@ -1172,7 +1156,7 @@ pub fn bit_7_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_7_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.d, 7);
     8
 }
@@ -1181,7 +1165,7 @@ pub fn bit_7_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_7_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.e, 7);
     8
 }
@@ -1190,7 +1174,7 @@ pub fn bit_7_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_7_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.h, 7);
     8
 }
@@ -1199,7 +1183,7 @@ pub fn bit_7_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_7_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.l, 7);
     8
 }
@@ -1208,8 +1192,8 @@ pub fn bit_7_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.test_bit(memory_map.cpu_get(cpu.registers.hl()), 7);
+pub fn bit_7_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.test_bit(bus.read(cpu.registers.hl()), 7);
     12
 }
 
@@ -1217,7 +1201,7 @@ pub fn bit_7_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: Z 0 1 -
-pub fn bit_7_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn bit_7_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.test_bit(cpu.registers.a, 7);
     8
 }
@@ -1226,7 +1210,7 @@ pub fn bit_7_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_0_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_0_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 0);
     8
 }
@@ -1235,7 +1219,7 @@ pub fn res_0_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_0_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_0_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 0);
     8
 }
@@ -1244,7 +1228,7 @@ pub fn res_0_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_0_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_0_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 0);
     8
 }
@@ -1253,7 +1237,7 @@ pub fn res_0_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_0_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_0_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 0);
     8
 }
@@ -1262,7 +1246,7 @@ pub fn res_0_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_0_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_0_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 0);
     8
 }
@@ -1271,7 +1255,7 @@ pub fn res_0_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_0_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_0_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 0);
     8
 }
@@ -1280,11 +1264,9 @@ pub fn res_0_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_0_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 0),
-    );
+pub fn res_0_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 0);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1292,7 +1274,7 @@ pub fn res_0_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_0_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_0_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 0);
     8
 }
@@ -1301,7 +1283,7 @@ pub fn res_0_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_1_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_1_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 1);
     8
 }
@@ -1310,7 +1292,7 @@ pub fn res_1_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_1_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_1_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 1);
     8
 }
@@ -1319,7 +1301,7 @@ pub fn res_1_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_1_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_1_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 1);
     8
 }
@@ -1328,7 +1310,7 @@ pub fn res_1_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_1_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_1_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 1);
     8
 }
@@ -1337,7 +1319,7 @@ pub fn res_1_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_1_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_1_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 1);
     8
 }
@@ -1346,7 +1328,7 @@ pub fn res_1_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_1_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_1_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 1);
     8
 }
@@ -1355,11 +1337,9 @@ pub fn res_1_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_1_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 1),
-    );
+pub fn res_1_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 1);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1367,7 +1347,7 @@ pub fn res_1_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_1_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_1_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 1);
     8
 }
@@ -1376,7 +1356,7 @@ pub fn res_1_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_2_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_2_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 2);
     8
 }
@@ -1385,7 +1365,7 @@ pub fn res_2_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_2_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_2_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 2);
     8
 }
@@ -1394,7 +1374,7 @@ pub fn res_2_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_2_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_2_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 2);
     8
 }
@@ -1403,7 +1383,7 @@ pub fn res_2_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_2_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_2_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 2);
     8
 }
@@ -1412,7 +1392,7 @@ pub fn res_2_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_2_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_2_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 2);
     8
 }
@@ -1421,7 +1401,7 @@ pub fn res_2_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_2_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_2_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 2);
     8
 }
@@ -1430,11 +1410,9 @@ pub fn res_2_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_2_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 2),
-    );
+pub fn res_2_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 2);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1442,7 +1420,7 @@ pub fn res_2_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_2_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_2_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 2);
     8
 }
@@ -1451,7 +1429,7 @@ pub fn res_2_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_3_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_3_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 3);
     8
 }
@@ -1460,7 +1438,7 @@ pub fn res_3_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_3_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_3_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 3);
     8
 }
@@ -1469,7 +1447,7 @@ pub fn res_3_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_3_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_3_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 3);
     8
 }
@@ -1478,7 +1456,7 @@ pub fn res_3_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_3_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_3_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 3);
     8
 }
@@ -1487,7 +1465,7 @@ pub fn res_3_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_3_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_3_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 3);
     8
 }
@@ -1496,7 +1474,7 @@ pub fn res_3_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_3_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_3_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 3);
     8
 }
@@ -1505,11 +1483,9 @@ pub fn res_3_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_3_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 3),
-    );
+pub fn res_3_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 3);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1517,7 +1493,7 @@ pub fn res_3_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_3_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_3_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 3);
     8
 }
@@ -1526,7 +1502,7 @@ pub fn res_3_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_4_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_4_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 4);
     8
 }
@@ -1535,7 +1511,7 @@ pub fn res_4_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_4_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_4_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 4);
     8
 }
@@ -1544,7 +1520,7 @@ pub fn res_4_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_4_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_4_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 4);
     8
 }
@@ -1553,7 +1529,7 @@ pub fn res_4_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_4_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_4_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 4);
     8
 }
@@ -1562,7 +1538,7 @@ pub fn res_4_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_4_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_4_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 4);
     8
 }
@@ -1571,7 +1547,7 @@ pub fn res_4_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_4_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_4_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 4);
     8
 }
@@ -1580,11 +1556,9 @@ pub fn res_4_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_4_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 4),
-    );
+pub fn res_4_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 4);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1592,7 +1566,7 @@ pub fn res_4_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_4_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_4_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 4);
     8
 }
@@ -1601,7 +1575,7 @@ pub fn res_4_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_5_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_5_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 5);
     8
 }
@@ -1610,7 +1584,7 @@ pub fn res_5_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_5_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_5_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 5);
     8
 }
@@ -1619,7 +1593,7 @@ pub fn res_5_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_5_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_5_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 5);
     8
 }
@@ -1628,7 +1602,7 @@ pub fn res_5_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_5_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_5_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 5);
     8
 }
@@ -1637,7 +1611,7 @@ pub fn res_5_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_5_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_5_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 5);
     8
 }
@@ -1646,7 +1620,7 @@ pub fn res_5_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_5_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_5_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 5);
     8
 }
@@ -1655,11 +1629,9 @@ pub fn res_5_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_5_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 5),
-    );
+pub fn res_5_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 5);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1667,7 +1639,7 @@ pub fn res_5_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_5_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_5_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 5);
     8
 }
@@ -1676,7 +1648,7 @@ pub fn res_5_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_6_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_6_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 6);
     8
 }
@@ -1685,7 +1657,7 @@ pub fn res_6_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_6_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_6_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 6);
     8
 }
@@ -1694,7 +1666,7 @@ pub fn res_6_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_6_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_6_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 6);
     8
 }
@@ -1703,7 +1675,7 @@ pub fn res_6_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_6_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_6_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 6);
     8
 }
@@ -1712,7 +1684,7 @@ pub fn res_6_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_6_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_6_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 6);
     8
 }
@@ -1721,7 +1693,7 @@ pub fn res_6_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_6_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_6_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 6);
     8
 }
@@ -1730,11 +1702,9 @@ pub fn res_6_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_6_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 6),
-    );
+pub fn res_6_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 6);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1742,7 +1712,7 @@ pub fn res_6_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_6_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_6_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 6);
     8
 }
@@ -1751,7 +1721,7 @@ pub fn res_6_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_7_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_7_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.reset_bit(cpu.registers.b, 7);
     8
 }
@@ -1760,7 +1730,7 @@ pub fn res_7_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_7_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_7_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.reset_bit(cpu.registers.c, 7);
     8
 }
@@ -1769,7 +1739,7 @@ pub fn res_7_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_7_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_7_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.reset_bit(cpu.registers.d, 7);
     8
 }
@@ -1778,7 +1748,7 @@ pub fn res_7_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_7_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_7_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.reset_bit(cpu.registers.e, 7);
     8
 }
@@ -1787,7 +1757,7 @@ pub fn res_7_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_7_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_7_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.reset_bit(cpu.registers.h, 7);
     8
 }
@@ -1796,7 +1766,7 @@ pub fn res_7_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_7_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_7_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.reset_bit(cpu.registers.l, 7);
     8
 }
@@ -1805,11 +1775,9 @@ pub fn res_7_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn res_7_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.reset_bit(memory_map.cpu_get(cpu.registers.hl()), 7),
-    );
+pub fn res_7_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.reset_bit(bus.read(cpu.registers.hl()), 7);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1817,7 +1785,7 @@ pub fn res_7_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn res_7_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn res_7_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.reset_bit(cpu.registers.a, 7);
     8
 }
@@ -1826,7 +1794,7 @@ pub fn res_7_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_0_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_0_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 0);
     8
 }
@@ -1835,7 +1803,7 @@ pub fn set_0_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_0_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_0_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 0);
     8
 }
@@ -1844,7 +1812,7 @@ pub fn set_0_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_0_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_0_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 0);
     8
 }
@@ -1853,7 +1821,7 @@ pub fn set_0_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_0_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_0_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 0);
     8
 }
@@ -1862,7 +1830,7 @@ pub fn set_0_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_0_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_0_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 0);
     8
 }
@@ -1871,7 +1839,7 @@ pub fn set_0_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_0_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_0_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 0);
     8
 }
@@ -1880,11 +1848,9 @@ pub fn set_0_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_0_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 0),
-    );
+pub fn set_0_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 0);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1892,7 +1858,7 @@ pub fn set_0_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_0_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_0_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 0);
     8
 }
@@ -1901,7 +1867,7 @@ pub fn set_0_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_1_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_1_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 1);
     8
 }
@@ -1910,7 +1876,7 @@ pub fn set_1_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_1_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_1_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 1);
     8
 }
@@ -1919,7 +1885,7 @@ pub fn set_1_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_1_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_1_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 1);
     8
 }
@@ -1928,7 +1894,7 @@ pub fn set_1_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_1_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_1_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 1);
     8
 }
@@ -1937,7 +1903,7 @@ pub fn set_1_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_1_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_1_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 1);
     8
 }
@@ -1946,7 +1912,7 @@ pub fn set_1_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_1_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_1_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 1);
     8
 }
@@ -1955,11 +1921,9 @@ pub fn set_1_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_1_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 1),
-    );
+pub fn set_1_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 1);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -1967,7 +1931,7 @@ pub fn set_1_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_1_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_1_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 1);
     8
 }
@@ -1976,7 +1940,7 @@ pub fn set_1_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_2_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_2_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 2);
     8
 }
@@ -1985,7 +1949,7 @@ pub fn set_2_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_2_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_2_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 2);
     8
 }
@@ -1994,7 +1958,7 @@ pub fn set_2_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_2_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_2_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 2);
     8
 }
@@ -2003,7 +1967,7 @@ pub fn set_2_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_2_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_2_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 2);
     8
 }
@@ -2012,7 +1976,7 @@ pub fn set_2_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_2_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_2_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 2);
     8
 }
@@ -2021,7 +1985,7 @@ pub fn set_2_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_2_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_2_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 2);
     8
 }
@@ -2030,11 +1994,9 @@ pub fn set_2_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_2_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 2),
-    );
+pub fn set_2_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 2);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -2042,7 +2004,7 @@ pub fn set_2_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_2_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_2_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 2);
     8
 }
@@ -2051,7 +2013,7 @@ pub fn set_2_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_3_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_3_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 3);
     8
 }
@@ -2060,7 +2022,7 @@ pub fn set_3_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_3_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_3_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 3);
     8
 }
@@ -2069,7 +2031,7 @@ pub fn set_3_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_3_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_3_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 3);
     8
 }
@@ -2078,7 +2040,7 @@ pub fn set_3_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_3_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_3_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 3);
     8
 }
@@ -2087,7 +2049,7 @@ pub fn set_3_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_3_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_3_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 3);
     8
 }
@@ -2096,7 +2058,7 @@ pub fn set_3_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_3_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_3_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 3);
     8
 }
@@ -2105,11 +2067,9 @@ pub fn set_3_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_3_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 3),
-    );
+pub fn set_3_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 3);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -2117,7 +2077,7 @@ pub fn set_3_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_3_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_3_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 3);
     8
 }
@@ -2126,7 +2086,7 @@ pub fn set_3_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_4_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_4_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 4);
     8
 }
@@ -2135,7 +2095,7 @@ pub fn set_4_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_4_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_4_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 4);
     8
 }
@@ -2144,7 +2104,7 @@ pub fn set_4_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_4_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_4_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 4);
     8
 }
@@ -2153,7 +2113,7 @@ pub fn set_4_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_4_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_4_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 4);
     8
 }
@@ -2162,7 +2122,7 @@ pub fn set_4_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_4_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_4_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 4);
     8
 }
@@ -2171,7 +2131,7 @@ pub fn set_4_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_4_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_4_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 4);
     8
 }
@@ -2180,11 +2140,9 @@ pub fn set_4_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_4_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 4),
-    );
+pub fn set_4_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 4);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -2192,7 +2150,7 @@ pub fn set_4_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_4_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_4_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 4);
     8
 }
@@ -2201,7 +2159,7 @@ pub fn set_4_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_5_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_5_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 5);
     8
 }
@@ -2210,7 +2168,7 @@ pub fn set_5_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_5_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_5_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 5);
     8
 }
@@ -2219,7 +2177,7 @@ pub fn set_5_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_5_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_5_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 5);
     8
 }
@@ -2228,7 +2186,7 @@ pub fn set_5_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_5_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_5_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 5);
     8
 }
@@ -2237,7 +2195,7 @@ pub fn set_5_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_5_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_5_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 5);
     8
 }
@@ -2246,7 +2204,7 @@ pub fn set_5_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_5_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_5_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 5);
     8
 }
@@ -2255,11 +2213,9 @@ pub fn set_5_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_5_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 5),
-    );
+pub fn set_5_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 5);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -2267,7 +2223,7 @@ pub fn set_5_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_5_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_5_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 5);
     8
 }
@@ -2276,7 +2232,7 @@ pub fn set_5_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_6_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_6_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 6);
     8
 }
@@ -2285,7 +2241,7 @@ pub fn set_6_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_6_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_6_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 6);
     8
 }
@@ -2294,7 +2250,7 @@ pub fn set_6_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_6_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_6_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 6);
     8
 }
@@ -2303,7 +2259,7 @@ pub fn set_6_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_6_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_6_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 6);
     8
 }
@@ -2312,7 +2268,7 @@ pub fn set_6_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_6_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_6_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 6);
     8
 }
@@ -2321,7 +2277,7 @@ pub fn set_6_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_6_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_6_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 6);
     8
 }
@@ -2330,11 +2286,9 @@ pub fn set_6_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_6_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 6),
-    );
+pub fn set_6_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 6);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -2342,7 +2296,7 @@ pub fn set_6_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_6_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_6_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 6);
     8
 }
@@ -2351,7 +2305,7 @@ pub fn set_6_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_7_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_7_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.set_bit(cpu.registers.b, 7);
     8
 }
@@ -2360,7 +2314,7 @@ pub fn set_7_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_7_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_7_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.set_bit(cpu.registers.c, 7);
     8
 }
@@ -2369,7 +2323,7 @@ pub fn set_7_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_7_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_7_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.set_bit(cpu.registers.d, 7);
     8
 }
@@ -2378,7 +2332,7 @@ pub fn set_7_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_7_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_7_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.set_bit(cpu.registers.e, 7);
     8
 }
@@ -2387,7 +2341,7 @@ pub fn set_7_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_7_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_7_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.set_bit(cpu.registers.h, 7);
     8
 }
@@ -2396,7 +2350,7 @@ pub fn set_7_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_7_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_7_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.set_bit(cpu.registers.l, 7);
     8
 }
@@ -2405,11 +2359,9 @@ pub fn set_7_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn set_7_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        cpu.registers.hl(),
-        cpu.set_bit(memory_map.cpu_get(cpu.registers.hl()), 7),
-    );
+pub fn set_7_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.set_bit(bus.read(cpu.registers.hl()), 7);
+    bus.write(cpu.registers.hl(), value);
     16
 }
 
@@ -2417,7 +2369,7 @@ pub fn set_7_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn set_7_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn set_7_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.set_bit(cpu.registers.a, 7);
     8
 }
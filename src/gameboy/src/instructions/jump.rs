@@ -1,13 +1,13 @@
 #![allow(dead_code, unused_variables)]
-use crate::memory_map::MemoryMap;
+use crate::bus::Bus;
 use crate::Cpu;
 
 /// JR r8 - 0x18 <br>
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn jr_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.pc = (cpu.pc as i32 + (cpu.get_immediate_u8(memory_map) as i8) as i32) as u16;
+pub fn jr_r8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.pc = (cpu.pc as i32 + (cpu.get_immediate_u8(bus) as i8) as i32) as u16;
     12
 }
 
@@ -15,9 +15,9 @@ pub fn jr_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12/8 <br>
 ///  Flags affected: - - - -
-pub fn jr_nz_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jr_nz_r8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 0 {
-        return jr_r8(cpu, memory_map);
+        return jr_r8(cpu, bus);
     }
 
     8
@@ -27,9 +27,9 @@ pub fn jr_nz_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12/8 <br>
 ///  Flags affected: - - - -
-pub fn jr_z_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jr_z_r8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 1 {
-        return jr_r8(cpu, memory_map);
+        return jr_r8(cpu, bus);
     }
 
     8
@@ -39,9 +39,9 @@ pub fn jr_z_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12/8 <br>
 ///  Flags affected: - - - -
-pub fn jr_nc_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jr_nc_r8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 0 {
-        return jr_r8(cpu, memory_map);
+        return jr_r8(cpu, bus);
     }
 
     8
@@ -51,9 +51,9 @@ pub fn jr_nc_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12/8 <br>
 ///  Flags affected: - - - -
-pub fn jr_c_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jr_c_r8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 1 {
-        return jr_r8(cpu, memory_map);
+        return jr_r8(cpu, bus);
     }
 
     8
@@ -63,9 +63,11 @@ pub fn jr_c_r8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 20/8 <br>
 ///  Flags affected: - - - -
-pub fn ret_nz(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ret_nz(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 0 {
-        ret(cpu, memory_map);
+        // Condition is checked in an internal M-cycle before returning.
+        bus.tick();
+        ret(cpu, bus);
         20
     } else {
         8
@@ -76,9 +78,9 @@ pub fn ret_nz(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 16/12 <br>
 ///  Flags affected: - - - -
-pub fn jp_nz_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jp_nz_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 0 {
-        return jp_a16(cpu, memory_map);
+        return jp_a16(cpu, bus);
     }
 
     12
@@ -88,8 +90,8 @@ pub fn jp_nz_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn jp_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.pc = cpu.get_immediate_u16(memory_map);
+pub fn jp_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.pc = cpu.get_immediate_u16(bus);
 
     16
 }
@@ -98,9 +100,9 @@ pub fn jp_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 24/12 <br>
 ///  Flags affected: - - - -
-pub fn call_nz_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn call_nz_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 0 {
-        return call_a16(cpu, memory_map);
+        return call_a16(cpu, bus);
     }
 
     12
@@ -110,8 +112,8 @@ pub fn call_nz_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_00h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x00);
+pub fn rst_00h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x00);
     16
 }
 
@@ -119,9 +121,11 @@ pub fn rst_00h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 20/8 <br>
 ///  Flags affected: - - - -
-pub fn ret_z(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ret_z(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 1 {
-        ret(cpu, memory_map);
+        // Condition is checked in an internal M-cycle before returning.
+        bus.tick();
+        ret(cpu, bus);
         20
     } else {
         8
@@ -132,8 +136,8 @@ pub fn ret_z(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn ret(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.pc = cpu.pop(memory_map);
+pub fn ret(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.pc = cpu.pop(bus);
     16
 }
 
@@ -141,9 +145,9 @@ pub fn ret(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 16/12 <br>
 ///  Flags affected: - - - -
-pub fn jp_z_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jp_z_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 1 {
-        return jp_a16(cpu, memory_map);
+        return jp_a16(cpu, bus);
     }
 
     12
@@ -153,9 +157,9 @@ pub fn jp_z_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 24/12 <br>
 ///  Flags affected: - - - -
-pub fn call_z_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn call_z_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_z() == 1 {
-        return call_a16(cpu, memory_map);
+        return call_a16(cpu, bus);
     }
 
     12
@@ -165,8 +169,9 @@ pub fn call_z_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 24 <br>
 ///  Flags affected: - - - -
-pub fn call_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, cpu.get_immediate_u16(memory_map));
+pub fn call_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let address = cpu.get_immediate_u16(bus);
+    cpu.call(bus, address);
 
     24
 }
@@ -175,8 +180,8 @@ pub fn call_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_08h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x08);
+pub fn rst_08h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x08);
     16
 }
 
@@ -184,9 +189,11 @@ pub fn rst_08h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 20/8 <br>
 ///  Flags affected: - - - -
-pub fn ret_nc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ret_nc(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 0 {
-        ret(cpu, memory_map);
+        // Condition is checked in an internal M-cycle before returning.
+        bus.tick();
+        ret(cpu, bus);
         20
     } else {
         8
@@ -197,9 +204,9 @@ pub fn ret_nc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 16/12 <br>
 ///  Flags affected: - - - -
-pub fn jp_nc_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jp_nc_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 0 {
-        return jp_a16(cpu, memory_map);
+        return jp_a16(cpu, bus);
     }
 
     12
@@ -209,9 +216,9 @@ pub fn jp_nc_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 24/12 <br>
 ///  Flags affected: - - - -
-pub fn call_nc_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn call_nc_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 0 {
-        return call_a16(cpu, memory_map);
+        return call_a16(cpu, bus);
     }
 
     12
@@ -221,8 +228,8 @@ pub fn call_nc_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_10h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x10);
+pub fn rst_10h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x10);
     16
 }
 
@@ -230,9 +237,11 @@ pub fn rst_10h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 20/8 <br>
 ///  Flags affected: - - - -
-pub fn ret_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ret_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 1 {
-        ret(cpu, memory_map);
+        // Condition is checked in an internal M-cycle before returning.
+        bus.tick();
+        ret(cpu, bus);
         20
     } else {
         8
@@ -243,19 +252,19 @@ pub fn ret_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn reti(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn reti(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // Return and enable interrupts (IME=1)
     cpu.enable_interrupts();
-    ret(cpu, memory_map)
+    ret(cpu, bus)
 }
 
 /// JP C,a16 - 0xDA <br>
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 16/12 <br>
 ///  Flags affected: - - - -
-pub fn jp_c_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jp_c_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 1 {
-        return jp_a16(cpu, memory_map);
+        return jp_a16(cpu, bus);
     }
 
     12
@@ -265,9 +274,9 @@ pub fn jp_c_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 24/12 <br>
 ///  Flags affected: - - - -
-pub fn call_c_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn call_c_a16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     if cpu.registers.get_cy() == 1 {
-        return call_a16(cpu, memory_map);
+        return call_a16(cpu, bus);
     }
 
     12
@@ -277,8 +286,8 @@ pub fn call_c_a16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_18h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x18);
+pub fn rst_18h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x18);
     16
 }
 
@@ -286,8 +295,8 @@ pub fn rst_18h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_20h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x20);
+pub fn rst_20h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x20);
     16
 }
 
@@ -295,7 +304,7 @@ pub fn rst_20h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn jp_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn jp_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.pc = cpu.registers.hl();
     4
 }
@@ -304,8 +313,8 @@ pub fn jp_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_28h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x28);
+pub fn rst_28h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x28);
     16
 }
 
@@ -313,8 +322,8 @@ pub fn rst_28h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_30h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x30);
+pub fn rst_30h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x30);
     16
 }
 
@@ -322,7 +331,7 @@ pub fn rst_30h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn rst_38h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.call(memory_map, 0x38);
+pub fn rst_38h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.call(bus, 0x38);
     16
 }
@@ -1,13 +1,14 @@
 #![allow(dead_code, unused_variables)]
-use crate::memory_map::{MemoryMap, OamCorruption};
+use crate::bus::Bus;
+use crate::memory_map::OamCorruption;
 use crate::Cpu;
 
 /// LD BC,d16 - 0x01 <br>
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn ld_bc_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.set_bc(cpu.get_immediate_u16(memory_map));
+pub fn ld_bc_d16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.set_bc(cpu.get_immediate_u16(bus));
     12
 }
 
@@ -15,8 +16,8 @@ pub fn ld_bc_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_bc_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.bc(), cpu.registers.a);
+pub fn ld_bc_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.bc(), cpu.registers.a);
     8
 }
 
@@ -24,8 +25,8 @@ pub fn ld_bc_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.b = cpu.get_immediate_u8(memory_map);
+pub fn ld_b_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.b = cpu.get_immediate_u8(bus);
     8
 }
 
@@ -33,8 +34,9 @@ pub fn ld_b_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 20 <br>
 ///  Flags affected: - - - -
-pub fn ld_a16_addr_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set_u16(cpu.get_immediate_u16(memory_map), cpu.sp);
+pub fn ld_a16_addr_sp(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let address = cpu.get_immediate_u16(bus);
+    bus.write_u16(address, cpu.sp);
     20
 }
 
@@ -42,8 +44,8 @@ pub fn ld_a16_addr_sp(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_bc_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.a = memory_map.cpu_get(cpu.registers.bc());
+pub fn ld_a_bc_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.a = bus.read(cpu.registers.bc());
     8
 }
 
@@ -51,8 +53,8 @@ pub fn ld_a_bc_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.c = cpu.get_immediate_u8(memory_map);
+pub fn ld_c_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.c = cpu.get_immediate_u8(bus);
     8
 }
 
@@ -60,8 +62,8 @@ pub fn ld_c_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn ld_de_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.set_de(cpu.get_immediate_u16(memory_map));
+pub fn ld_de_d16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.set_de(cpu.get_immediate_u16(bus));
     12
 }
 
@@ -69,8 +71,8 @@ pub fn ld_de_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_de_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.de(), cpu.registers.a);
+pub fn ld_de_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.de(), cpu.registers.a);
     8
 }
 
@@ -78,8 +80,8 @@ pub fn ld_de_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.d = cpu.get_immediate_u8(memory_map);
+pub fn ld_d_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.d = cpu.get_immediate_u8(bus);
     8
 }
 
@@ -87,8 +89,8 @@ pub fn ld_d_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_de_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.a = memory_map.cpu_get(cpu.registers.de());
+pub fn ld_a_de_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.a = bus.read(cpu.registers.de());
     8
 }
 
@@ -96,8 +98,8 @@ pub fn ld_a_de_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.e = cpu.get_immediate_u8(memory_map);
+pub fn ld_e_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.e = cpu.get_immediate_u8(bus);
     8
 }
 
@@ -105,8 +107,8 @@ pub fn ld_e_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.set_hl(cpu.get_immediate_u16(memory_map));
+pub fn ld_hl_d16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.set_hl(cpu.get_immediate_u16(bus));
     12
 }
 
@@ -114,12 +116,12 @@ pub fn ld_hl_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_plus_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.disable_oam_corruption();
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.a);
+pub fn ld_hl_plus_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.disable_oam_corruption();
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
+    bus.write(cpu.registers.hl(), cpu.registers.a);
     cpu.registers.set_hl(cpu.registers.hl().wrapping_add(1));
-    memory_map.enable_oam_corruption();
+    bus.enable_oam_corruption();
     8
 }
 
@@ -127,8 +129,8 @@ pub fn ld_hl_plus_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.h = cpu.get_immediate_u8(memory_map);
+pub fn ld_h_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.h = cpu.get_immediate_u8(bus);
     8
 }
 
@@ -136,12 +138,12 @@ pub fn ld_h_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_hl_plus_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.disable_oam_corruption();
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
-    cpu.registers.a = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_a_hl_plus_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.disable_oam_corruption();
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
+    cpu.registers.a = bus.read(cpu.registers.hl());
     cpu.registers.set_hl(cpu.registers.hl().wrapping_add(1));
-    memory_map.enable_oam_corruption();
+    bus.enable_oam_corruption();
 
     8
 }
@@ -150,8 +152,8 @@ pub fn ld_a_hl_plus_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.l = cpu.get_immediate_u8(memory_map);
+pub fn ld_l_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.l = cpu.get_immediate_u8(bus);
     8
 }
 
@@ -159,8 +161,8 @@ pub fn ld_l_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn ld_sp_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.sp = cpu.get_immediate_u16(memory_map);
+pub fn ld_sp_d16(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.sp = cpu.get_immediate_u16(bus);
     12
 }
 
@@ -168,12 +170,12 @@ pub fn ld_sp_d16(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_minus_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.disable_oam_corruption();
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.a);
+pub fn ld_hl_minus_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.disable_oam_corruption();
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
+    bus.write(cpu.registers.hl(), cpu.registers.a);
     cpu.registers.set_hl(cpu.registers.hl().wrapping_sub(1));
-    memory_map.enable_oam_corruption();
+    bus.enable_oam_corruption();
     8
 }
 
@@ -181,8 +183,9 @@ pub fn ld_hl_minus_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.get_immediate_u8(memory_map));
+pub fn ld_hl_addr_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let value = cpu.get_immediate_u8(bus);
+    bus.write(cpu.registers.hl(), value);
     12
 }
 
@@ -190,12 +193,12 @@ pub fn ld_hl_addr_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_hl_minus_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.disable_oam_corruption();
-    memory_map.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
-    cpu.registers.a = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_a_hl_minus_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.disable_oam_corruption();
+    bus.try_corrupt_oam(cpu.registers.hl(), OamCorruption::IncDecRead);
+    cpu.registers.a = bus.read(cpu.registers.hl());
     cpu.registers.set_hl(cpu.registers.hl().wrapping_sub(1));
-    memory_map.enable_oam_corruption();
+    bus.enable_oam_corruption();
     8
 }
 
@@ -203,8 +206,8 @@ pub fn ld_a_hl_minus_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.a = cpu.get_immediate_u8(memory_map);
+pub fn ld_a_d8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.a = cpu.get_immediate_u8(bus);
     8
 }
 
@@ -212,7 +215,7 @@ pub fn ld_a_d8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_b_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.registers.b;
     4
 }
@@ -221,7 +224,7 @@ pub fn ld_b_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_b_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.registers.c;
     4
 }
@@ -230,7 +233,7 @@ pub fn ld_b_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_b_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.registers.d;
     4
 }
@@ -239,7 +242,7 @@ pub fn ld_b_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_b_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.registers.e;
     4
 }
@@ -248,7 +251,7 @@ pub fn ld_b_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_b_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.registers.h;
     4
 }
@@ -257,7 +260,7 @@ pub fn ld_b_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_b_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.registers.l;
     4
 }
@@ -266,8 +269,8 @@ pub fn ld_b_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.b = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_b_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.b = bus.read(cpu.registers.hl());
     8
 }
 
@@ -275,7 +278,7 @@ pub fn ld_b_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_b_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_b_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.b = cpu.registers.a;
     4
 }
@@ -284,7 +287,7 @@ pub fn ld_b_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_c_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.registers.b;
     4
 }
@@ -293,7 +296,7 @@ pub fn ld_c_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_c_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.registers.c;
     4
 }
@@ -302,7 +305,7 @@ pub fn ld_c_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_c_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.registers.d;
     4
 }
@@ -311,7 +314,7 @@ pub fn ld_c_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_c_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.registers.e;
     4
 }
@@ -320,7 +323,7 @@ pub fn ld_c_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_c_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.registers.h;
     4
 }
@@ -329,7 +332,7 @@ pub fn ld_c_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_c_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.registers.l;
     4
 }
@@ -338,8 +341,8 @@ pub fn ld_c_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.c = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_c_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.c = bus.read(cpu.registers.hl());
     8
 }
 
@@ -347,7 +350,7 @@ pub fn ld_c_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_c_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.c = cpu.registers.a;
     4
 }
@@ -356,7 +359,7 @@ pub fn ld_c_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_d_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.registers.b;
     4
 }
@@ -365,7 +368,7 @@ pub fn ld_d_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_d_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.registers.c;
     4
 }
@@ -374,7 +377,7 @@ pub fn ld_d_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_d_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.registers.d;
     4
 }
@@ -383,7 +386,7 @@ pub fn ld_d_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_d_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.registers.e;
     4
 }
@@ -392,7 +395,7 @@ pub fn ld_d_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_d_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.registers.h;
     4
 }
@@ -401,7 +404,7 @@ pub fn ld_d_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_d_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.registers.l;
     4
 }
@@ -410,8 +413,8 @@ pub fn ld_d_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.d = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_d_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.d = bus.read(cpu.registers.hl());
     8
 }
 
@@ -419,7 +422,7 @@ pub fn ld_d_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_d_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_d_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.d = cpu.registers.a;
     4
 }
@@ -428,7 +431,7 @@ pub fn ld_d_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_e_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.registers.b;
     4
 }
@@ -437,7 +440,7 @@ pub fn ld_e_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_e_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.registers.c;
     4
 }
@@ -446,7 +449,7 @@ pub fn ld_e_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_e_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.registers.d;
     4
 }
@@ -455,7 +458,7 @@ pub fn ld_e_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_e_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.registers.e;
     4
 }
@@ -464,7 +467,7 @@ pub fn ld_e_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_e_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.registers.h;
     4
 }
@@ -473,7 +476,7 @@ pub fn ld_e_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_e_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.registers.l;
     4
 }
@@ -482,8 +485,8 @@ pub fn ld_e_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.e = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_e_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.e = bus.read(cpu.registers.hl());
     8
 }
 
@@ -491,7 +494,7 @@ pub fn ld_e_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_e_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_e_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.e = cpu.registers.a;
     4
 }
@@ -500,7 +503,7 @@ pub fn ld_e_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_h_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.registers.b;
     4
 }
@@ -509,7 +512,7 @@ pub fn ld_h_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_h_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.registers.c;
     4
 }
@@ -518,7 +521,7 @@ pub fn ld_h_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_h_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.registers.d;
     4
 }
@@ -527,7 +530,7 @@ pub fn ld_h_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_h_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.registers.e;
     4
 }
@@ -536,7 +539,7 @@ pub fn ld_h_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_h_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.registers.h;
     4
 }
@@ -545,7 +548,7 @@ pub fn ld_h_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_h_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.registers.l;
     4
 }
@@ -554,8 +557,8 @@ pub fn ld_h_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.h = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_h_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.h = bus.read(cpu.registers.hl());
     8
 }
 
@@ -563,7 +566,7 @@ pub fn ld_h_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_h_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_h_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.h = cpu.registers.a;
     4
 }
@@ -572,7 +575,7 @@ pub fn ld_h_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_l_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.registers.b;
     4
 }
@@ -581,7 +584,7 @@ pub fn ld_l_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_l_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.registers.c;
     4
 }
@@ -590,7 +593,7 @@ pub fn ld_l_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_l_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.registers.d;
     4
 }
@@ -599,7 +602,7 @@ pub fn ld_l_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_l_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.registers.e;
     4
 }
@@ -608,7 +611,7 @@ pub fn ld_l_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_l_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.registers.h;
     4
 }
@@ -617,7 +620,7 @@ pub fn ld_l_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_l_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.registers.l;
     4
 }
@@ -626,8 +629,8 @@ pub fn ld_l_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.l = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_l_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.l = bus.read(cpu.registers.hl());
     8
 }
 
@@ -635,7 +638,7 @@ pub fn ld_l_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_l_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_l_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.l = cpu.registers.a;
     4
 }
@@ -644,8 +647,8 @@ pub fn ld_l_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.b);
+pub fn ld_hl_addr_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.hl(), cpu.registers.b);
     8
 }
 
@@ -653,8 +656,8 @@ pub fn ld_hl_addr_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.c);
+pub fn ld_hl_addr_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.hl(), cpu.registers.c);
     8
 }
 
@@ -662,8 +665,8 @@ pub fn ld_hl_addr_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.d);
+pub fn ld_hl_addr_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.hl(), cpu.registers.d);
     8
 }
 
@@ -671,8 +674,8 @@ pub fn ld_hl_addr_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.e);
+pub fn ld_hl_addr_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.hl(), cpu.registers.e);
     8
 }
 
@@ -680,8 +683,8 @@ pub fn ld_hl_addr_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.h);
+pub fn ld_hl_addr_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.hl(), cpu.registers.h);
     8
 }
 
@@ -689,8 +692,8 @@ pub fn ld_hl_addr_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.l);
+pub fn ld_hl_addr_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.hl(), cpu.registers.l);
     8
 }
 
@@ -698,8 +701,8 @@ pub fn ld_hl_addr_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_hl_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.registers.hl(), cpu.registers.a);
+pub fn ld_hl_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(cpu.registers.hl(), cpu.registers.a);
     8
 }
 
@@ -707,7 +710,7 @@ pub fn ld_hl_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_a_b(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.registers.b;
     4
 }
@@ -716,7 +719,7 @@ pub fn ld_a_b(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_a_c(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.registers.c;
     4
 }
@@ -725,7 +728,7 @@ pub fn ld_a_c(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_a_d(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.registers.d;
     4
 }
@@ -734,7 +737,7 @@ pub fn ld_a_d(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_a_e(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.registers.e;
     4
 }
@@ -743,7 +746,7 @@ pub fn ld_a_e(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_a_h(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.registers.h;
     4
 }
@@ -752,7 +755,7 @@ pub fn ld_a_h(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_a_l(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.registers.l;
     4
 }
@@ -761,8 +764,8 @@ pub fn ld_a_l(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.a = memory_map.cpu_get(cpu.registers.hl());
+pub fn ld_a_hl_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.a = bus.read(cpu.registers.hl());
     8
 }
 
@@ -770,7 +773,7 @@ pub fn ld_a_hl_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_a_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.registers.a = cpu.registers.a;
     4
 }
@@ -779,8 +782,8 @@ pub fn ld_a_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn pop_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    let result = cpu.pop(memory_map);
+pub fn pop_bc(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let result = cpu.pop(bus);
     cpu.registers.set_bc(result);
     12
 }
@@ -789,8 +792,8 @@ pub fn pop_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn push_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.push(memory_map, cpu.registers.bc());
+pub fn push_bc(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.push(bus, cpu.registers.bc());
     16
 }
 
@@ -798,8 +801,8 @@ pub fn push_bc(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn pop_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    let result = cpu.pop(memory_map);
+pub fn pop_de(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let result = cpu.pop(bus);
     cpu.registers.set_de(result);
     12
 }
@@ -808,8 +811,8 @@ pub fn pop_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn push_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.push(memory_map, cpu.registers.de());
+pub fn push_de(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.push(bus, cpu.registers.de());
     16
 }
 
@@ -817,11 +820,9 @@ pub fn push_de(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn ldh_a8_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(
-        0xFF00 + cpu.get_immediate_u8(memory_map) as u16,
-        cpu.registers.a,
-    );
+pub fn ldh_a8_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let address = 0xFF00 + cpu.get_immediate_u8(bus) as u16;
+    bus.write(address, cpu.registers.a);
     12
 }
 
@@ -829,8 +830,8 @@ pub fn ldh_a8_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn pop_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    let result = cpu.pop(memory_map);
+pub fn pop_hl(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let result = cpu.pop(bus);
     cpu.registers.set_hl(result);
     12
 }
@@ -839,8 +840,8 @@ pub fn pop_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_c_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(0xFF00 + cpu.registers.c as u16, cpu.registers.a);
+pub fn ld_c_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    bus.write(0xFF00 + cpu.registers.c as u16, cpu.registers.a);
     8
 }
 
@@ -848,8 +849,8 @@ pub fn ld_c_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn push_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.push(memory_map, cpu.registers.hl());
+pub fn push_hl(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.push(bus, cpu.registers.hl());
     16
 }
 
@@ -857,8 +858,9 @@ pub fn push_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn ld_a16_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    memory_map.cpu_set(cpu.get_immediate_u16(memory_map), cpu.registers.a);
+pub fn ld_a16_addr_a(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let address = cpu.get_immediate_u16(bus);
+    bus.write(address, cpu.registers.a);
     16
 }
 
@@ -866,8 +868,9 @@ pub fn ld_a16_addr_a(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: - - - -
-pub fn ldh_a_a8_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.a = memory_map.cpu_get(0xFF00 + cpu.get_immediate_u8(memory_map) as u16);
+pub fn ldh_a_a8_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let address = 0xFF00 + cpu.get_immediate_u8(bus) as u16;
+    cpu.registers.a = bus.read(address);
     12
 }
 
@@ -875,8 +878,8 @@ pub fn ldh_a_a8_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: Z N H C
-pub fn pop_af(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    let result = cpu.pop(memory_map);
+pub fn pop_af(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let result = cpu.pop(bus);
     cpu.registers.set_af(result);
     12
 }
@@ -885,8 +888,8 @@ pub fn pop_af(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_c_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.a = memory_map.cpu_get(0xFF00 + cpu.registers.c as u16);
+pub fn ld_a_c_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.registers.a = bus.read(0xFF00 + cpu.registers.c as u16);
     8
 }
 
@@ -894,8 +897,8 @@ pub fn ld_a_c_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn push_af(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.push(memory_map, cpu.registers.af());
+pub fn push_af(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    cpu.push(bus, cpu.registers.af());
     16
 }
 
@@ -903,10 +906,10 @@ pub fn push_af(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 2 <br>
 ///  Duration in cycles: 12 <br>
 ///  Flags affected: 0 0 H C
-pub fn ld_hl_sp_plusr8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_hl_sp_plusr8(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // https://www.reddit.com/r/EmuDev/comments/y51i1c/game_boy_dealing_with_carry_flags_when_handling/
 
-    let val = cpu.get_immediate_u8(memory_map);
+    let val = cpu.get_immediate_u8(bus);
 
     let half_carry = ((cpu.sp & 0xF) + ((val as u16) & 0xF)) > 0xF;
     let carry = ((cpu.sp & 0xFF) + ((val as u16) & 0xFF)) > 0xFF;
@@ -923,7 +926,7 @@ pub fn ld_hl_sp_plusr8(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 8 <br>
 ///  Flags affected: - - - -
-pub fn ld_sp_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ld_sp_hl(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     cpu.sp = cpu.registers.hl();
     8
 }
@@ -932,7 +935,8 @@ pub fn ld_sp_hl(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 3 <br>
 ///  Duration in cycles: 16 <br>
 ///  Flags affected: - - - -
-pub fn ld_a_a16_addr(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
-    cpu.registers.a = memory_map.cpu_get(cpu.get_immediate_u16(memory_map));
+pub fn ld_a_a16_addr(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
+    let address = cpu.get_immediate_u16(bus);
+    cpu.registers.a = bus.read(address);
     16
 }
@@ -1,12 +1,12 @@
 #![allow(dead_code, unused_variables)]
-use crate::memory_map::MemoryMap;
+use crate::bus::Bus;
 use crate::Cpu;
 
 /// NOP - 0x00 <br>
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn nop(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn nop(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // No operation.
     4
 }
@@ -15,9 +15,9 @@ pub fn nop(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn stop_0(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn stop_0(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // Halt CPU & LCD display until button pressed.
-    // cpu.stop(); // TODO
+    cpu.stop(bus);
     4
 }
 
@@ -25,9 +25,9 @@ pub fn stop_0(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn halt(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn halt(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     // Power down CPU until an interrupt occurs.
-    cpu.halt();
+    cpu.halt(bus);
     4
 }
 
@@ -35,7 +35,7 @@ pub fn halt(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn prefix_cb(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn prefix_cb(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     4
 }
 
@@ -43,7 +43,7 @@ pub fn prefix_cb(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn di(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn di(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     /*
         This instruction disables interrupts but not
         immediately. Interrupts are disabled after
@@ -58,14 +58,14 @@ pub fn di(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
 ///  Length in bytes: 1 <br>
 ///  Duration in cycles: 4 <br>
 ///  Flags affected: - - - -
-pub fn ei(cpu: &mut Cpu, memory_map: &mut MemoryMap) -> u8 {
+pub fn ei(cpu: &mut Cpu, bus: &mut dyn Bus) -> u8 {
     /*
         Enable interrupts. This intruction enables interrupts
         but not immediately. Interrupts are enabled after
         instruction after EI is executed.
     */
 
-    cpu.enable_interrupts();
+    cpu.enable_interrupts_delayed();
 
     4
 }
@@ -1,19 +1,26 @@
+pub mod bus;
 pub mod cpu;
+pub mod disassembler;
 pub mod instructions;
 mod mbc;
 pub mod memory_map;
 pub mod ppu;
 mod registers;
+pub mod symbols;
+pub mod trace;
 
-use std::{error::Error, path::Path, time::Duration};
+use std::{error::Error, io::Write, path::Path, time::Duration};
 
+use bus::Bus;
 use cpu::Cpu;
+use disassembler::DisassembledInstruction;
 use instructions::{Instruction, INSTRUCTIONS, PREFIX_CB_INSTRUCTIONS};
-use memoffset::offset_of;
 use memory_map::MemoryMap;
 use ppu::Ppu;
+use symbols::Symbols;
+use trace::CpuTrace;
 
-use self::memory_map::{Io, MemSyncer, SyncMem};
+use self::memory_map::Io;
 
 use std::ops::BitOr;
 
@@ -24,8 +31,6 @@ use std::ops::BitOr;
 
 const CPU_CLOCK_RATE: u32 = 4_194_304;
 
-const DIV_REGISTER_CLOCK_RATE: u32 = 16_384;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct JoypadKeys(u8);
 
@@ -66,6 +71,21 @@ impl AsRef<str> for JoypadKeys {
     }
 }
 
+/// Statistics of a frame that is run with [`Gameboy::run_frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameStats {
+    /// Number of the frame in the ppu frame counter.
+    pub frame: u64,
+    /// Base clock cycles that are run in this frame.
+    pub cycles: u64,
+    /// Cpu instructions that are run in this frame. Interrupt dispatches and the M-cycles of a halted cpu are not counted.
+    pub instructions: u32,
+    /// Whether the frame is completed with a VBlank. LCD doesn't produce frames while it's off.
+    pub completed: bool,
+    /// Whether the LCD shows a blank screen instead of the screen buffer.
+    pub display_blank: bool,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct Gameboy {
@@ -74,14 +94,24 @@ pub struct Gameboy {
 
     pub memory_map: MemoryMap,
 
-    base_clock: u32,
-    // Cpu clock cycles are different in latency.
-    // So we need a variable to store remainder cpu cycles after an emulator cycle.
-    remainder_cpu_cycles: u32,
+    // Master clock in T-cycles since the start. It only increases and never wraps around.
+    base_clock: u64,
+    // Cpu instructions are different in latency and can't be interrupted in the middle.
+    // So we store the master clock cycle an emulator cycle runs to, the last instruction can run past it.
+    target_clock: u64,
+    // Cpu instructions that are executed since the start, interrupt dispatches and idle M-cycles are not counted.
+    instruction_count: u64,
 
-    dma_transfer_start: Option<u32>,
+    // Last state of the timer input that increments TIMA on its falling edge.
+    timer_signal: bool,
+    tima_overflow: bool,
 
     joypad_keys: JoypadKeys,
+
+    cpu_trace: CpuTrace,
+
+    // Labels of the rom for the debugger, they are loaded from a symbol file.
+    pub symbols: Symbols,
 }
 
 impl Gameboy {
@@ -115,84 +145,57 @@ impl Gameboy {
             memory_map: MemoryMap::after_boot(),
 
             base_clock: 0,
-            remainder_cpu_cycles: 0,
+            target_clock: 0,
+            instruction_count: 0,
 
-            dma_transfer_start: None,
+            timer_signal: false,
+            tima_overflow: false,
 
             joypad_keys: JoypadKeys::NONE,
-        }
-    }
-
-    #[allow(dead_code)]
-    pub fn load_cartidge(&mut self, path: impl AsRef<Path>) {
-        self.memory_map.load_rom(path);
-        self.memory_map.mem_syncer = MemSyncer::new(offset_of!(Gameboy, memory_map));
-    }
-
-    fn increment_tima(&mut self) {
-        /*
-            FF07 - TAC - Timer Control (R/W)
-            Bit 2    - Timer Stop  (0=Stop, 1=Start)
-            Bits 1-0 - Input Clock Select
-                00:   4096 Hz    (~4194 Hz SGB)
-                01: 262144 Hz  (~268400 Hz SGB)
-                10:  65536 Hz   (~67110 Hz SGB)
-                11:  16384 Hz   (~16780 Hz SGB)
-        */
-        let tac = self.memory_map.cpu_get_io(Io::TAC);
-
-        let timer_clock = match tac & 0x3 {
-            0 => 4096,
-            1 => 262144,
-            2 => 65536,
-            3 => 16384,
-            _ => unreachable!(),
-        };
-
-        // Check if the timer is stopped.
-        if tac & 0x4 != 0 {
-            if self.base_clock % (CPU_CLOCK_RATE / timer_clock) != 0 {
-                return;
-            }
 
-            // Calculate the new timer value.
-            let tima = self.memory_map.cpu_get_io(Io::TIMA);
+            cpu_trace: CpuTrace::default(),
 
-            if tima < 0xFF {
-                self.memory_map.cpu_set_io(Io::TIMA, tima + 1);
-            } else {
-                /*
-                    Note:
-                    If a TMA write is executed on the same cycle as the content of TMA is transferred to TIMA due to a timer overflow,
-                    the old value is transferred to TIMA.
-                */
-
-                // TIMA overflow occured. Set the TIMA register to TMA and request a Timer interrupt.
-                self.memory_map
-                    .cpu_set_io(Io::TIMA, self.memory_map.cpu_get_io(Io::TMA));
-                self.memory_map
-                    .cpu_set_io(Io::IF, self.memory_map.cpu_get_io(Io::IF) | 0x4);
-            }
+            symbols: Symbols::default(),
         }
     }
 
-    fn update_peripherals(&mut self) {
-        if self.cpu.is_stopped() {
-            return;
-        }
+    #[allow(dead_code)]
+    pub fn load_cartidge(&mut self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        self.memory_map.load_rom(path)?;
 
-        self.update_joypad();
+        if self.memory_map.boot_rom.is_empty() {
+            // Some roms rely on the logo the boot rom leaves in VRAM.
+            self.memory_map.load_boot_logo();
+        }
 
-        self.increment_tima();
+        Ok(())
+    }
 
-        if self.base_clock % (CPU_CLOCK_RATE / DIV_REGISTER_CLOCK_RATE) == 0 {
-            self.memory_map.increment_div();
-        }
+    // Borrows the cpu and the rest of the gameboy separately, the cpu accesses the rest through the bus.
+    fn split_cpu(&mut self) -> (&mut Cpu, System<'_>) {
+        let Self {
+            cpu,
+            ppu,
+            memory_map,
+            base_clock,
+            timer_signal,
+            tima_overflow,
+            joypad_keys,
+            cpu_trace,
+            ..
+        } = self;
+
+        let system = System {
+            ppu,
+            memory_map,
+            base_clock,
+            timer_signal,
+            tima_overflow,
+            joypad_keys: *joypad_keys,
+            cpu_trace,
+        };
 
-        self.ppu.cycle(
-            &mut self.memory_map,
-            (ppu::PPU_CLOCK_RATE * 4) / CPU_CLOCK_RATE,
-        );
+        (cpu, system)
     }
 
     /// Function for private implementation of the emulator cycle.
@@ -200,74 +203,58 @@ impl Gameboy {
     /// Function tries to do a complete emulation such as running Cpu, Ppu and counting timers such as DIV and TIMA.
     fn cycle_impl<T: FnMut(&Self) -> bool>(
         &mut self,
-        base_clock_cycles: u32,
+        base_clock_cycles: u64,
         mut on_cpu_cycle: Option<T>,
     ) {
         // Reset the triggered watch variable every cycle.
         self.memory_map.triggered_watch = None;
 
-        self.base_clock = self.base_clock % CPU_CLOCK_RATE;
-        self.ppu.clock_cycles = self.ppu.clock_cycles % ppu::PPU_ONE_FRAME;
+        // Cycles overworked by the last instruction of the previous emulator cycle are already emulated.
+        self.target_clock += base_clock_cycles;
 
-        let start_base_clock = self.base_clock;
-        let start_cpu_clock_cycles = self.cpu.clock_cycles;
-
-        while self.base_clock - start_base_clock < base_clock_cycles {
-            self.update_peripherals();
-
-            // We check each time if the cpu clock is lower than base clock.
-            // This is done because Cpu instructions have different instruction latencies.
-            if self.cpu.clock_cycles - start_cpu_clock_cycles + self.remainder_cpu_cycles
-                <= self.base_clock - start_base_clock
-            {
-                // Check if the DMA transfer is over.
-                if let Some(dma_start) = self.dma_transfer_start {
-                    let diff = if dma_start > self.base_clock {
-                        self.base_clock - (dma_start % CPU_CLOCK_RATE)
-                    } else {
-                        self.base_clock - dma_start
-                    };
-
-                    // TODO: 160?
-                    if diff >= 160 {
-                        self.dma_transfer_start = None;
-                        self.memory_map.on_dma_transfer = false;
-                    } else {
-                        // Set DMA transfer true for restricting CPU memory access.
-                        self.memory_map.on_dma_transfer = true;
-                    }
-                }
+        while self.base_clock < self.target_clock {
+            let was_stopped = self.cpu.is_stopped();
+            let instruction_start = self.base_clock;
+            let cpu_clock_cycles = self.cpu.clock_cycles;
 
-                self.cpu.cycle(&mut self.memory_map);
+            // Cpu accesses the rest of the gameboy through the bus.
+            // Every bus access advances the peripherals by an M-cycle before it happens.
+            let (cpu, mut system) = self.split_cpu();
+            let executed = cpu.cycle(&mut system);
 
-                // Memory is triggered in user given condition. Stop execution.
-                if self.memory_map.triggered_watch.is_some() {
-                    self.base_clock += 4;
-                    break;
-                }
+            // Internal M-cycles at the end of the instruction don't access the bus.
+            // A halted, stopped or locked cpu still spends an M-cycle.
+            let cpu_cycles = cpu.clock_cycles.wrapping_sub(cpu_clock_cycles).max(4) as u64;
+            while *system.base_clock - instruction_start < cpu_cycles {
+                system.idle_tick(cpu);
+            }
 
-                // Check if the DMA transfer is started.
-                if self.memory_map.on_dma_transfer && self.dma_transfer_start.is_none() {
-                    self.dma_transfer_start = Some(self.base_clock);
-                }
-                // Reset DMA transfer so PPU can access memory.
-                self.memory_map.on_dma_transfer = false;
-
-                // Check if there is an boot rom in the memory map.
-                // Boot rom hands over the control to cartridges rom in 0x100.
-                if !self.memory_map.boot_rom.is_empty() && self.cpu.pc >= 0x100 {
-                    // Clear the boot room.
-                    self.memory_map.clear_boot_rom();
-                }
+            if executed {
+                self.instruction_count += 1;
+            }
+
+            if !was_stopped && self.cpu.is_stopped() {
+                // LCD is blanked while the cpu is in stop mode.
+                self.ppu.clear_screen();
+            }
+
+            // Memory is triggered in user given condition. Stop execution.
+            if self.memory_map.triggered_watch.is_some() {
+                break;
+            }
+
+            // Check if there is an boot rom in the memory map.
+            // Boot rom hands over the control to cartridges rom in 0x100.
+            if !self.memory_map.boot_rom.is_empty() && self.cpu.pc >= 0x100 {
+                // Clear the boot room.
+                self.memory_map.clear_boot_rom();
+            }
 
-                if let Some(func) = &mut on_cpu_cycle {
-                    if func(self) {
-                        self.base_clock += 4;
-                        break;
-                    }
+            if let Some(func) = &mut on_cpu_cycle {
+                if func(self) {
+                    break;
                 }
             }
-            self.base_clock += 4;
         }
 
         /*
@@ -277,17 +264,10 @@ impl Gameboy {
                                        ^                           ^ Overworked cpu instruction in this emulator cycle.
                                        Remainder cpu instruction from last emulator cycle.
             Because that cpu instructions are wary in latency sometimes they overwork(run more than the base clock).
-            And then we store these overworked cycles and don't run the cpu until same amount of cycles passes.
+            And then these overworked cycles are subtracted from the next emulator cycle by keeping the target clock.
+            When the execution is stopped early the cycles that aren't run are dropped.
         */
-        let total_cpu_cycles =
-            self.cpu.clock_cycles - start_cpu_clock_cycles + self.remainder_cpu_cycles;
-        let total_base_clock_cycles = self.base_clock - start_base_clock;
-
-        if total_cpu_cycles > total_base_clock_cycles {
-            self.remainder_cpu_cycles = total_cpu_cycles - total_base_clock_cycles;
-        } else {
-            self.remainder_cpu_cycles = 0;
-        }
+        self.target_clock = self.target_clock.min(self.base_clock);
     }
 
     /// Cycles the emulator with given elapsed time parameter.
@@ -301,7 +281,7 @@ impl Gameboy {
         elapsed_time: Duration,
         on_cpu_cycle: T,
     ) {
-        let base_clock_cycles = (CPU_CLOCK_RATE as f32 * elapsed_time.as_secs_f32()) as u32;
+        let base_clock_cycles = Self::duration_to_cycles(elapsed_time);
 
         self.cycle_impl(base_clock_cycles, Some(on_cpu_cycle))
     }
@@ -312,7 +292,7 @@ impl Gameboy {
     ///     For example if elapsed_time is 1 second function will effectively run 4 194 304 cpu cycles.
     ///   
     pub fn cycle(&mut self, elapsed_time: Duration) {
-        let base_clock_cycles = (CPU_CLOCK_RATE as f32 * elapsed_time.as_secs_f32()) as u32;
+        let base_clock_cycles = Self::duration_to_cycles(elapsed_time);
 
         self.cycle_impl::<fn(&Self) -> bool>(base_clock_cycles, None);
     }
@@ -320,40 +300,79 @@ impl Gameboy {
     /// Cycles the emulator in the smallest possible step.
     /// Function is guaranteed to run exactly 1 cpu instruction.
     pub fn cycle_once(&mut self) {
+        // Cycles overworked by the previous instruction would stop the next one from running.
+        self.target_clock = self.base_clock;
+
         // Rust requires a type to be passed on. So we pass a dummy function pointer.
-        self.cycle_impl::<fn(&Self) -> bool>(self.remainder_cpu_cycles + 4, None)
+        self.cycle_impl::<fn(&Self) -> bool>(4, None)
     }
 
-    pub fn update_joypad_keys(&mut self, keys: JoypadKeys) {
-        self.joypad_keys = keys;
+    /// Cycles the emulator for an exact number of T-cycles of the master clock.
+    /// Cycles run past the end by the last instruction are subtracted from the next call,
+    ///     so running in many small steps ends up at the same state as running in a single step.
+    /// # Arguments
+    /// * `cycles` - T-cycles to run, 4 194 304 cycles are a second.
+    pub fn run_cycles(&mut self, cycles: u64) {
+        self.cycle_impl::<fn(&Self) -> bool>(cycles, None);
     }
 
-    fn update_joypad(&mut self) {
-        let joyp = self.memory_map.cpu_get_io(Io::JOYP);
+    /// Cycles the emulator for an exact number of T-cycles of the master clock.
+    /// # Arguments
+    /// * `cycles` - T-cycles to run, 4 194 304 cycles are a second.
+    ///
+    /// * `on_cpu_cycle` - A closure that will be called after every cpu instruction, execution stops when it returns true.
+    pub fn debug_run_cycles<T: FnMut(&Self) -> bool>(&mut self, cycles: u64, on_cpu_cycle: T) {
+        self.cycle_impl(cycles, Some(on_cpu_cycle));
+    }
 
-        let keys = if joyp & 0x30 == 0x30 {
-            // Reset joypad.
-            self.memory_map.cpu_set_io(Io::JOYP, 0xFF);
-            return;
-        } else if joyp & 0x10 != 0 {
-            // Button keys
-            !self.joypad_keys.0 & 0xF
-        } else if joyp & 0x20 != 0 {
-            // Direction keys
-            !(self.joypad_keys.0 >> 4) & 0xF
-        } else {
-            return;
-        };
+    /// T-cycles of the master clock that are run since the emulator is created.
+    pub fn total_cycles(&self) -> u64 {
+        self.base_clock
+    }
 
-        self.memory_map.cpu_set_io(Io::JOYP, (joyp & 0xF0) | keys);
+    // Converts emulator time to T-cycles with integer arithmetic, so no cycles are lost in rounding errors.
+    fn duration_to_cycles(duration: Duration) -> u64 {
+        (duration.as_nanos() * CPU_CLOCK_RATE as u128 / 1_000_000_000) as u64
+    }
 
-        // Handle interrupt
-        if keys != 0xF {
-            self.memory_map
-                .cpu_set_io(Io::IF, self.memory_map.cpu_get_io(Io::IF) | 0x10);
+    /// Runs the emulator until the ppu enters the next VBlank, when the screen buffer holds a complete frame.
+    /// While the LCD is off it runs for the duration of a frame instead.
+    /// Execution also stops early if a memory watch is triggered.
+    pub fn run_frame(&mut self) -> FrameStats {
+        let start_base_clock = self.base_clock;
+        let start_frame = self.ppu.frame_count();
+        let start_instruction_count = self.instruction_count;
+
+        self.cycle_impl(
+            ppu::PPU_ONE_FRAME as u64,
+            Some(|emulator: &Self| emulator.ppu.frame_count() != start_frame),
+        );
+
+        FrameStats {
+            frame: self.ppu.frame_count(),
+            cycles: self.base_clock - start_base_clock,
+            instructions: (self.instruction_count - start_instruction_count) as u32,
+            completed: self.ppu.frame_count() != start_frame,
+            display_blank: self.ppu.is_display_blank(),
         }
     }
 
+    /// Writes a line for every cpu instruction in the Gameboy Doctor format.
+    /// The writer should be buffered, there are about a million lines in a second of emulation.
+    pub fn set_cpu_trace(&mut self, writer: impl Write + 'static) {
+        self.cpu_trace = CpuTrace::new(writer);
+    }
+
+    /// Stops the cpu trace and flushes its writer.
+    pub fn clear_cpu_trace(&mut self) {
+        self.cpu_trace.flush();
+        self.cpu_trace = CpuTrace::default();
+    }
+
+    pub fn update_joypad_keys(&mut self, keys: JoypadKeys) {
+        self.joypad_keys = keys;
+    }
+
     /// A instruction fetch and decode without any side effects.
     /// # Arguments
     /// * `address` - Starting address of the instruction
@@ -366,11 +385,206 @@ impl Gameboy {
             INSTRUCTIONS[opcode as usize]
         }
     }
+
+    /// Decodes the instruction with its operands without any side effects.
+    /// # Arguments
+    /// * `address` - Starting address of the instruction
+    pub fn disassemble(&self, address: u16) -> DisassembledInstruction {
+        disassembler::disassemble(address, |address| self.memory_map.get(address))
+    }
+
+    /// Label of an address in the bank that is currently mapped at it.
+    /// Symbol files that put all of WRAM in bank 0 are also accepted.
+    pub fn label_at(&self, address: u16) -> Option<&str> {
+        let bank = self.memory_map.bank_at(address);
+
+        self.symbols.label(bank, address).or_else(|| match address {
+            0x4000..=0x7FFF => None,
+            _ => self.symbols.label(0, address),
+        })
+    }
+}
+
+/*
+    Rest of the gameboy as seen by the cpu, it is borrowed from the emulator while the cpu runs.
+    Timers, joypad and the PPU advance with every M-cycle of the bus.
+*/
+struct System<'a> {
+    ppu: &'a mut Ppu,
+    memory_map: &'a mut MemoryMap,
+
+    base_clock: &'a mut u64,
+
+    timer_signal: &'a mut bool,
+    tima_overflow: &'a mut bool,
+
+    joypad_keys: JoypadKeys,
+
+    cpu_trace: &'a mut CpuTrace,
+}
+
+impl System<'_> {
+    fn update_timer(&mut self) {
+        /*
+            FF07 - TAC - Timer Control (R/W)
+            Bit 2    - Timer Stop  (0=Stop, 1=Start)
+            Bits 1-0 - Input Clock Select
+                00:   4096 Hz    (~4194 Hz SGB)
+                01: 262144 Hz  (~268400 Hz SGB)
+                10:  65536 Hz   (~67110 Hz SGB)
+                11:  16384 Hz   (~16780 Hz SGB)
+        */
+        if *self.tima_overflow {
+            /*
+                Note:
+                If a TMA write is executed on the same cycle as the content of TMA is transferred to TIMA due to a timer overflow,
+                the old value is transferred to TIMA.
+            */
+
+            // TIMA overflowed in the last M-cycle. Set the TIMA register to TMA and request a Timer interrupt.
+            *self.tima_overflow = false;
+            self.memory_map
+                .set_io(Io::TIMA, self.memory_map.get_io(Io::TMA));
+            self.memory_map
+                .set_io(Io::IF, self.memory_map.get_io(Io::IF) | 0x4);
+        }
+
+        self.memory_map.increment_system_counter(4);
+
+        let tac = self.memory_map.get_io(Io::TAC);
+
+        // Timer clocks are taken from a bit of the system counter.
+        let timer_bit = match tac & 0x3 {
+            0 => 9,
+            1 => 3,
+            2 => 5,
+            3 => 7,
+            _ => unreachable!(),
+        };
+
+        // TIMA is incremented on the falling edge of the selected bit while the timer is enabled.
+        // This is why writes to DIV and TAC can also increment it.
+        let timer_signal =
+            tac & 0x4 != 0 && (self.memory_map.system_counter() >> timer_bit) & 0x1 != 0;
+
+        if *self.timer_signal && !timer_signal {
+            let tima = self.memory_map.get_io(Io::TIMA);
+
+            if tima < 0xFF {
+                self.memory_map.set_io(Io::TIMA, tima + 1);
+            } else {
+                // TIMA reads 0x00 for an M-cycle before it is reloaded.
+                self.memory_map.set_io(Io::TIMA, 0x00);
+                *self.tima_overflow = true;
+            }
+        }
+
+        *self.timer_signal = timer_signal;
+    }
+
+    fn update_peripherals(&mut self) {
+        self.update_joypad();
+        self.update_timer();
+        self.memory_map.update_serial();
+        self.memory_map.update_oam_dma();
+
+        self.ppu
+            .cycle(self.memory_map, (ppu::PPU_CLOCK_RATE * 4) / CPU_CLOCK_RATE);
+    }
+
+    // An M-cycle outside of the cpu bus accesses, the peripherals are halted while the cpu is in stop mode.
+    fn idle_tick(&mut self, cpu: &mut Cpu) {
+        if cpu.is_stopped() {
+            self.update_joypad();
+
+            // Timers and the PPU are halted in stop mode.
+            // It is exited when one of the selected joypad lines goes low.
+            if self.memory_map.get_io(Io::JOYP) & 0xF == 0xF {
+                *self.base_clock += 4;
+                return;
+            }
+
+            cpu.exit_stop_mode();
+        }
+
+        self.tick();
+    }
+
+    fn update_joypad(&mut self) {
+        let joyp = self.memory_map.cpu_get_io(Io::JOYP);
+
+        // Both key groups can be selected at the same time.
+        let mut keys = 0;
+
+        if joyp & 0x20 == 0 {
+            // Button keys
+            keys |= self.joypad_keys.0 & 0xF;
+        }
+        if joyp & 0x10 == 0 {
+            // Direction keys
+            keys |= self.joypad_keys.0 >> 4;
+        }
+
+        // Pressed keys are read as 0.
+        let keys = !keys & 0xF;
+
+        self.memory_map
+            .cpu_set_io(Io::JOYP, 0xC0 | (joyp & 0x30) | keys);
+
+        // Handle interrupt
+        if keys != 0xF {
+            self.memory_map
+                .cpu_set_io(Io::IF, self.memory_map.cpu_get_io(Io::IF) | 0x10);
+        }
+    }
 }
 
-impl SyncMem for Gameboy {
-    fn sync(&mut self) {
-        self.base_clock += 4;
+impl Bus for System<'_> {
+    fn read(&mut self, address: u16) -> u8 {
+        self.tick();
+        self.memory_map.cpu_get(address)
+    }
+
+    fn write(&mut self, address: u16, value: u8) {
+        if (Io::LCDC as u16..=Io::WX as u16).contains(&address) {
+            self.memory_map.ppu_io_write = Some((address, value));
+        }
+
+        self.tick();
+        self.memory_map.ppu_io_write = None;
+        self.memory_map.cpu_set(address, value);
+
+        if address == Io::LCDC as _ || address == Io::STAT as _ || address == Io::LYC as _ {
+            self.ppu.update_stat(self.memory_map);
+        }
+    }
+
+    fn tick(&mut self) {
         self.update_peripherals();
+        *self.base_clock += 4;
+    }
+
+    fn get(&self, address: u16) -> u8 {
+        self.memory_map.get(address)
+    }
+
+    fn set(&mut self, address: u16, value: u8) {
+        self.memory_map.set(address, value);
+    }
+
+    fn on_instruction(&mut self, cpu: &Cpu) {
+        self.cpu_trace.trace(cpu, self.memory_map);
+    }
+
+    fn try_corrupt_oam(&mut self, address: u16, corruption: memory_map::OamCorruption) {
+        self.memory_map.try_corrupt_oam(address, corruption);
+    }
+
+    fn enable_oam_corruption(&mut self) {
+        self.memory_map.enable_oam_corruption();
+    }
+
+    fn disable_oam_corruption(&mut self) {
+        self.memory_map.disable_oam_corruption();
     }
 }
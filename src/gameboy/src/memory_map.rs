@@ -13,102 +13,14 @@ Memory map of the gameboy(from pandocs: http://bgb.bircd.org/pandocs.htm):
     FF80-FFFE   High RAM (HRAM)
     FFFF        Interrupt Enable Register
 */
-use memoffset::offset_of;
-use std::{
-    cell::{RefCell, UnsafeCell},
-    error::Error,
-    marker::PhantomData,
-    path::Path,
-    ptr::copy_nonoverlapping,
-};
+use std::{cell::RefCell, error::Error, path::Path, ptr::copy_nonoverlapping};
 use strum_macros::{AsRefStr, EnumIter};
 
 use super::{
     mbc::{self, Mbc},
-    Gameboy,
+    ppu::Mode,
 };
 
-pub trait SyncMem {
-    fn sync(&mut self);
-}
-
-pub struct MemSyncer<T: SyncMem> {
-    phantom: PhantomData<T>,
-    offset_address: Option<usize>,
-    is_syncing: UnsafeCell<bool>,
-    old_is_syncing: UnsafeCell<bool>,
-}
-
-impl<T: SyncMem> MemSyncer<T> {
-    // Creates a MemSyncer with given offset_address.
-    // Parameter must be the offset address of the caller struct and its MemoryMap.
-    pub fn new(offset_address: usize) -> Self {
-        Self {
-            phantom: PhantomData::default(),
-            offset_address: Some(offset_address),
-            is_syncing: UnsafeCell::new(false),
-            old_is_syncing: UnsafeCell::new(false),
-        }
-    }
-
-    pub fn open_sync(&mut self) {
-        *self.is_syncing.get_mut() = true;
-    }
-
-    pub fn close_sync(&mut self) {
-        *self.is_syncing.get_mut() = false;
-    }
-
-    fn sync_start(&self) -> bool {
-        // TODO: Safety
-        unsafe {
-            if !*self.is_syncing.get() {
-                return false;
-            }
-
-            *self.old_is_syncing.get() = *self.is_syncing.get();
-            *self.is_syncing.get() = false;
-
-            if let Some(offset_address) = self.offset_address {
-                (*((self as *const Self as *const u8)
-                    .sub(offset_of!(MemoryMap, mem_syncer) + offset_address)
-                    as *mut T))
-                    .sync();
-            }
-
-            true
-        }
-    }
-
-    fn sync_end(&self) {
-        unsafe {
-            *self.is_syncing.get() = *self.old_is_syncing.get();
-        }
-    }
-}
-
-impl<T: SyncMem> Default for MemSyncer<T> {
-    fn default() -> Self {
-        Self {
-            phantom: PhantomData::default(),
-            offset_address: None,
-            is_syncing: UnsafeCell::new(false),
-            old_is_syncing: UnsafeCell::new(false),
-        }
-    }
-}
-
-impl<T: SyncMem> Clone for MemSyncer<T> {
-    fn clone(&self) -> Self {
-        Self {
-            phantom: PhantomData::default(),
-            offset_address: self.offset_address,
-            is_syncing: UnsafeCell::new(unsafe { *self.is_syncing.get() }),
-            old_is_syncing: UnsafeCell::new(unsafe { *self.old_is_syncing.get() }),
-        }
-    }
-}
-
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum OamCorruption {
     Read,
@@ -116,6 +28,14 @@ pub enum OamCorruption {
     IncDecRead,
 }
 
+// OAM DMA transfer copies a byte from the source to OAM every M-cycle.
+#[derive(Clone, Copy)]
+struct OamDma {
+    source: u16,
+    copied: u16, // Number of bytes copied to OAM.
+    value: u8,   // Last byte read from the source.
+}
+
 #[repr(u16)]
 #[allow(dead_code)]
 #[derive(Clone, Copy, Debug, EnumIter, AsRefStr, PartialEq)]
@@ -189,11 +109,13 @@ pub struct MemoryMap {
     high_ram: [u8; 0x7F],
     ier: u8, // Interrupt Enable Register
 
-    mbc: Box<dyn Mbc>,
+    system_counter: u16, // Internal counter of the timer, DIV register is its upper 8 bits.
 
-    pub mem_syncer: MemSyncer<Gameboy>,
+    mbc: Box<dyn Mbc>,
 
     pub current_oam_row: Option<u16>, // Current row of the OAM in PPU.
+    pub ppu_mode: Mode, // Mode that blocks VRAM and OAM accesses. It can be ahead of the mode reported in STAT.
+    pub ppu_write_mode: Mode, // Mode that blocks VRAM and OAM writes. It lags behind the mode that blocks the reads.
     oam_corruption_enabled: bool, // When set to true OAM corruptions will be disabled in cpu_get and cpu_set functions.
 
     pub boot_rom: Vec<u8>,
@@ -201,10 +123,28 @@ pub struct MemoryMap {
     pub memory_watches: Vec<(u16, Option<u8>)>,
     pub triggered_watch: Option<usize>,
 
-    pub on_dma_transfer: bool,
+    oam_dma: Option<OamDma>,
+    oam_dma_start: Option<(u16, u8)>, // Source address and remaining M-cycles of a requested transfer before it starts.
 
     pub vram_changed: bool,
     pub oam_changed: bool,
+
+    // Set when cpu writes to STAT for emulating the DMG STAT write bug.
+    pub stat_written: bool,
+
+    // Address and value of a cpu write to a ppu register in the current M-cycle.
+    // Ppu sees the new value in the middle of the M-cycle, before the write is completed.
+    pub ppu_io_write: Option<(u16, u8)>,
+
+    // Bytes that are sent through the serial port with the internal clock.
+    // There is no link cable, so the transfers are only recorded. Test roms print their results this way.
+    pub serial_output: Vec<u8>,
+    serial_bits: u8,     // Bits that are left to shift out in the current transfer.
+    serial_signal: bool, // Last state of the serial clock that shifts a bit on its falling edge.
+
+    // LY reads as this value for the cpu instead of the current line.
+    // Gameboy Doctor logs are recorded with LY fixed at 0x90, so the LY polling loops run the same.
+    pub ly_override: Option<u8>,
 }
 
 impl MemoryMap {
@@ -219,10 +159,13 @@ impl MemoryMap {
             high_ram: [0u8; 0x7F],
             ier: 0u8,
 
+            system_counter: 0,
+
             mbc: Box::new(mbc::NoMbc) as Box<dyn Mbc>,
 
-            mem_syncer: MemSyncer::default(),
             current_oam_row: None,
+            ppu_mode: Mode::HBlank,
+            ppu_write_mode: Mode::HBlank,
             oam_corruption_enabled: true,
 
             boot_rom: Vec::new(),
@@ -230,10 +173,20 @@ impl MemoryMap {
             memory_watches: Vec::new(),
             triggered_watch: None,
 
-            on_dma_transfer: false,
+            oam_dma: None,
+            oam_dma_start: None,
 
             vram_changed: false,
             oam_changed: false,
+
+            stat_written: false,
+            ppu_io_write: None,
+
+            serial_output: Vec::new(),
+            serial_bits: 0,
+            serial_signal: false,
+
+            ly_override: None,
         }
     }
 
@@ -275,22 +228,42 @@ impl MemoryMap {
         memory
     }
 
-    pub fn load_rom<T: AsRef<Path>>(&mut self, path: T) {
-        let rom = std::fs::read(path).unwrap();
+    /// Loads a cartridge rom and sets up its memory bank controller.
+    /// Returns an error when the file can't be read, its header is invalid or its cartridge type is not supported.
+    pub fn load_rom<T: AsRef<Path>>(&mut self, path: T) -> Result<(), Box<dyn Error>> {
+        let rom = std::fs::read(path)?;
+
+        if rom.len() < 0x150 {
+            return Err(format!("rom is {} bytes, too small to have a header", rom.len()).into());
+        }
 
         let cartridge_type = rom[0x147];
+
+        // ROM size is 32KB shifted left by the header value.
+        if rom[0x148] > 0x8 {
+            return Err(format!("rom size {:#04X} is not valid", rom[0x148]).into());
+        }
         let rom_bank_count = 1 << (rom[0x148] + 1);
 
+        if rom.len() > rom_bank_count * 0x4000 {
+            return Err(format!(
+                "rom is {} bytes, larger than the {} banks in its header",
+                rom.len(),
+                rom_bank_count
+            )
+            .into());
+        }
+
         let ram_bank_count = match rom[0x149] {
             0 => 0,
             0x2 => 1,
             0x3 => 4,
             0x4 => 16,
             0x5 => 8,
-            0x1 | _ => panic!("ram size(0x149) is not valid"),
+            ram_size => return Err(format!("ram size {ram_size:#04X} is not valid").into()),
         };
 
-        self.mbc = match cartridge_type {
+        let mbc: Box<dyn Mbc> = match cartridge_type {
             0x00 => Box::new(mbc::NoMbc),
             0x01 | 0x2 | 0x3 => Box::new(mbc::Mbc1::new(rom_bank_count, ram_bank_count)),
             // 0x05 | 0x06 => MBC2,
@@ -299,18 +272,55 @@ impl MemoryMap {
             // 0x19 | 0x1A | 0x1B | 0x1C | 0x1D | 0x1E => MBC5,
             // 0x20 => MBC6,
             // 0x22 => MBC7
-            _ => unimplemented!(),
+            _ => {
+                return Err(format!("cartridge type {cartridge_type:#04X} is not supported").into())
+            }
         };
 
+        self.mbc = mbc;
+
         self.rom_banks.resize(rom_bank_count, [0u8; 0x4000]);
         self.external_ram.resize(ram_bank_count, [0u8; 0x2000]);
         self.vrams.resize(1, [0u8; 0x2000]);
         self.wrams.resize(2, [0u8; 0x1000]);
 
         unsafe {
-            // TODO: SAFETY
+            // SAFETY: rom is checked to fit in the rom banks above.
             copy_nonoverlapping(rom.as_ptr(), self.rom_banks.as_mut_ptr() as _, rom.len());
         }
+
+        Ok(())
+    }
+
+    // Loads the logo from the cartridge header into VRAM like the DMG boot rom does.
+    pub fn load_boot_logo(&mut self) {
+        // Each bit of the logo is doubled horizontally and vertically, a nibble becomes 2 rows of a tile.
+        for (index, &byte) in self.rom_banks[0][0x104..0x134].iter().enumerate() {
+            for (nibble_index, nibble) in [byte >> 4, byte & 0xF].into_iter().enumerate() {
+                let row = (0..4).fold(0u8, |row, bit| {
+                    row | (((nibble >> bit) & 0x1) * 0x3) << (bit * 2)
+                });
+                let address = 0x10 + index * 8 + nibble_index * 4;
+
+                self.vrams[0][address] = row;
+                self.vrams[0][address + 2] = row;
+            }
+        }
+
+        // Registered trademark symbol after the logo.
+        for (index, row) in [0x3C, 0x42, 0xB9, 0xA5, 0xB9, 0xA5, 0x42, 0x3C]
+            .into_iter()
+            .enumerate()
+        {
+            self.vrams[0][0x190 + index * 2] = row;
+        }
+
+        // Tile map of the logo, 2 rows of 12 tiles with the trademark symbol at the end of the first row.
+        for tile in 0..12u8 {
+            self.vrams[0][0x1904 + tile as usize] = tile + 1;
+            self.vrams[0][0x1924 + tile as usize] = tile + 13;
+        }
+        self.vrams[0][0x1910] = 0x19;
     }
 
     pub fn load_boot_rom<T: AsRef<Path>>(&mut self, path: T) -> Result<(), Box<dyn Error>> {
@@ -322,6 +332,16 @@ impl MemoryMap {
         self.boot_rom.clear();
     }
 
+    // Bank that is mapped at the address, numbered like the banks in the RGBDS symbol files.
+    pub fn bank_at(&self, address: u16) -> u16 {
+        match address {
+            0x4000..=0x7FFF => self.mbc.get_rom_bank() as u16,
+            0xA000..=0xBFFF => self.mbc.get_ram_bank().unwrap_or(0) as u16,
+            0xD000..=0xDFFF => 1,
+            _ => 0,
+        }
+    }
+
     // General I/O. Can be used to get memory without any restriction.
     pub fn get(&self, address: u16) -> u8 {
         let address = address as usize;
@@ -412,6 +432,9 @@ impl MemoryMap {
             // FEA0-FEFF   Not Usable
         } else if address < 0xFF80 {
             // FF00-FF7F   I/O Ports
+            if address == Io::DIV as _ {
+                self.system_counter = (value as u16) << 8;
+            }
             self.io_ports[address - 0xFF00] = value;
         } else if address < 0xFFFF {
             self.high_ram[address - 0xFF80] = value;
@@ -447,21 +470,19 @@ impl MemoryMap {
 
     // CPU I/O.
     pub fn cpu_set(&mut self, address: u16, mut value: u8) {
-        let sync_start = self.mem_syncer.sync_start();
-
         let lcd_disabled = (self.cpu_get_io(Io::LCDC) & 0x80) == 0;
 
         if self.oam_corruption_enabled {
             self.try_corrupt_oam(address, OamCorruption::Write);
         }
 
-        let can_set = if self.on_dma_transfer && (address < 0xFF80 || address == 0xFFFF) {
-            // CPU can access only HRAM (memory at FF80-FFFE) during DMA transfer.
+        let can_set = if self.oam_dma_bus_conflict(address).is_some() {
+            // Bus is used by the DMA transfer.
             false
         } else if address >= 0x8000 && address < 0xA000 {
             // VRAM
             // Ppu is in pixel transfer mode.
-            let can_set = lcd_disabled || self.cpu_get_io(Io::STAT) & 0x3 != 0x3;
+            let can_set = lcd_disabled || self.ppu_write_mode != Mode::PixelTransfer;
             if can_set {
                 self.vram_changed = true;
             }
@@ -470,7 +491,8 @@ impl MemoryMap {
             // OAM
             // Ppu is in pixel transfer or OAM search mode.
 
-            let can_set = lcd_disabled || self.cpu_get_io(Io::STAT) & 0x2 == 0;
+            let can_set = lcd_disabled
+                || !matches!(self.ppu_write_mode, Mode::OamSearch | Mode::PixelTransfer);
             if can_set {
                 self.oam_changed = true;
             }
@@ -478,8 +500,8 @@ impl MemoryMap {
         } else if address == Io::DMA as _ {
             // DMA: Writing to this register launches a DMA transfer
             // It takes 640 cpu clock cycles for the DMA transfer to be complete.
-            self.dma_transfer(value);
-            false
+            self.start_oam_dma(value);
+            true
         } else {
             true
         };
@@ -487,6 +509,19 @@ impl MemoryMap {
         if address == Io::DIV as _ {
             // DIV: Divider register, writing any value to this register resets it to 0x00
             value = 0;
+        } else if address == Io::STAT as _ {
+            // Mode and coincidence flag bits of STAT are read only.
+            value = (value & 0x78) | (self.get(address) & 0x07);
+            self.stat_written = true;
+        } else if address == Io::SC as _ {
+            if value & 0x81 == 0x81 {
+                // Transfer is started with the internal clock.
+                self.serial_output.push(self.get(Io::SB as _));
+                self.serial_bits = 8;
+            } else {
+                // Without a clock from another gameboy an external clock transfer never completes.
+                self.serial_bits = 0;
+            }
         }
 
         if can_set {
@@ -499,47 +534,47 @@ impl MemoryMap {
                         watch_address == address as u16
                             && watch_value.map_or(true, |watch_value| watch_value == value)
                     });
-        }
-
-        self.mbc.set(address as u16, value);
 
-        if sync_start {
-            self.mem_syncer.sync_end();
+            // Writes that the DMA takes the bus from don't reach the cartridge either.
+            self.mbc.set(address as u16, value);
         }
     }
 
     pub fn cpu_get(&self, address: u16) -> u8 {
-        let sync_start = self.mem_syncer.sync_start();
-
         if self.oam_corruption_enabled {
             self.try_corrupt_oam(address, OamCorruption::Read);
         }
 
+        if let Some(value) = self.oam_dma_bus_conflict(address) {
+            return value;
+        }
+
         let mut value = self.get(address);
 
-        if self.on_dma_transfer && (address < 0xFF80 || address == 0xFFFF) {
-            // CPU can access only HRAM (memory at FF80-FFFE) during DMA transfer.
-            value = 0xFF;
+        if address == Io::IF as _ {
+            // Upper 3 bits of the IF register are unused and always read as 1.
+            value |= 0xE0;
+        } else if address == Io::STAT as _ {
+            // Bit 7 of STAT is unused and always read as 1.
+            value |= 0x80;
+        } else if address == Io::LY as _ {
+            value = self.ly_override.unwrap_or(value);
         }
 
         if address >= 0x8000 && address < 0xA000 {
             // VRAM
-            if self.cpu_get_io(Io::STAT) & 0x3 == 0x3 {
+            if self.ppu_mode == Mode::PixelTransfer {
                 // Ppu is in pixel transfer mode.
                 value = 0xFF;
             }
         } else if address >= 0xFE00 && address < 0xFEA0 {
             // OAM
-            if self.cpu_get_io(Io::STAT) & 0x2 != 0 {
+            if matches!(self.ppu_mode, Mode::OamSearch | Mode::PixelTransfer) {
                 // Ppu is in pixel transfer or OAM search mode.
                 value = 0xFF;
             }
         }
 
-        if sync_start {
-            self.mem_syncer.sync_end();
-        }
-
         value
     }
 
@@ -585,14 +620,10 @@ impl MemoryMap {
         */
 
         let lcdc = self.cpu_get_io(Io::LCDC);
-        let stat = self.cpu_get_io(Io::STAT);
 
         if lcdc & 0x80 == 0 {
             // LCDC off
             0xFF
-        } else if stat & 0x3 != 0x3 {
-            // Mode is not 3(pixel transfer)
-            0xFF
         } else {
             self.vrams[0][address as usize - 0x8000]
         }
@@ -607,25 +638,100 @@ impl MemoryMap {
     }
 
     // DIV register is a special I/O port that only cpu can write.
-    // When programmer tries to write to this register it automatically set to 0.
-    pub fn increment_div(&mut self) {
-        self.io_ports[Io::DIV as usize - 0xFF00] =
-            self.io_ports[Io::DIV as usize - 0xFF00].wrapping_add(1);
+    // When programmer tries to write to this register the whole system counter is set to 0.
+    pub fn increment_system_counter(&mut self, cycles: u16) {
+        self.system_counter = self.system_counter.wrapping_add(cycles);
+        self.io_ports[Io::DIV as usize - 0xFF00] = (self.system_counter >> 8) as u8;
     }
 
-    pub fn dma_transfer(&mut self, source: u8) {
-        // TODO: XX(source) = $00 to $DF
-        if source > 0xDF {
-            return;
+    pub fn system_counter(&self) -> u16 {
+        self.system_counter
+    }
+
+    /*
+        Serial clock is 8192Hz with the internal clock, it is taken from a bit of the system counter.
+        A bit of SB is shifted out on every falling edge and a 1 is shifted in, as there is no other gameboy.
+        After 8 bits the transfer is completed, bit 7 of SC is cleared and a Serial interrupt is requested.
+        Must be called every M-cycle.
+    */
+    pub fn update_serial(&mut self) {
+        let serial_signal = (self.system_counter >> 8) & 0x1 != 0;
+
+        if self.serial_signal && !serial_signal && self.serial_bits > 0 {
+            self.serial_bits -= 1;
+            self.set_io(Io::SB, (self.get_io(Io::SB) << 1) | 0x1);
+
+            if self.serial_bits == 0 {
+                self.set_io(Io::SC, self.get_io(Io::SC) & 0x7F);
+                self.set_io(Io::IF, self.get_io(Io::IF) | 0x8);
+            }
+        }
+
+        self.serial_signal = serial_signal;
+    }
+
+    pub fn start_oam_dma(&mut self, source: u8) {
+        // Transfer starts after a 1 M-cycle delay.
+        // If a transfer is already running it continues until then.
+        self.oam_dma_start = Some(((source as u16) << 8, 2));
+    }
+
+    // Copies the next byte of the OAM DMA transfer. Must be called every M-cycle.
+    pub fn update_oam_dma(&mut self) {
+        if matches!(self.oam_dma, Some(dma) if dma.copied == 0xA0) {
+            self.oam_dma = None;
+        }
+
+        if let Some((source, delay)) = self.oam_dma_start {
+            if delay > 1 {
+                self.oam_dma_start = Some((source, delay - 1));
+            } else {
+                self.oam_dma_start = None;
+                self.oam_dma = Some(OamDma {
+                    source,
+                    copied: 0,
+                    value: 0xFF,
+                });
+            }
         }
 
-        let source = (source as u16) << 8;
+        if let Some(mut dma) = self.oam_dma {
+            let mut address = dma.source + dma.copied;
+
+            if address >= 0xE000 {
+                // DMA reads from the external bus, where 0xE000-0xFFFF is mirrored to WRAM.
+                address -= 0x2000;
+            }
+
+            dma.value = self.get(address);
+            self.oam.borrow_mut()[dma.copied as usize] = dma.value;
+            dma.copied += 1;
 
-        for i in 0..0xA0 {
-            self.cpu_set(0xFE00 + i, self.cpu_get(source + i));
+            self.oam_dma = Some(dma);
+            self.oam_changed = true;
         }
+    }
+
+    /*
+        During OAM DMA the cpu can't access OAM and reads it as 0xFF.
+        DMA uses either the external bus(ROM, external RAM, WRAM) or the VRAM bus depending on its source.
+        Cpu reads the byte that DMA is transferring from the bus that DMA uses, writes to it are ignored.
+        HRAM and I/O registers are always accessible.
+    */
+    fn oam_dma_bus_conflict(&self, address: u16) -> Option<u8> {
+        let dma = self.oam_dma.as_ref()?;
 
-        self.on_dma_transfer = true;
+        let is_vram_bus = |address: u16| (0x8000..0xA000).contains(&address);
+
+        if (0xFE00..0xFF00).contains(&address) {
+            Some(0xFF)
+        } else if address >= 0xFF00 {
+            None
+        } else if is_vram_bus(address) == is_vram_bus(dma.source) {
+            Some(dma.value)
+        } else {
+            None
+        }
     }
 
     /*
@@ -651,7 +757,9 @@ impl MemoryMap {
 
         if let Some(current_oam_row) = self.current_oam_row {
             if corruption == OamCorruption::IncDecRead {
-                if current_oam_row < 20 {
+                // From pandocs: This corruption will not happen if the accessed row is one of the first four,
+                // as well as if it's the last row.
+                if (4..19).contains(&current_oam_row) {
                     let start = 0xFE00 + (current_oam_row - 1) as u16 * 8;
 
                     let a = self.get_u16(start - 8); // First word two rows before the currently accessed row.
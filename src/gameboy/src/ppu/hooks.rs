@@ -0,0 +1,67 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::memory_map::{Io, MemoryMap};
+
+use super::Mode;
+
+// Values of the ppu registers at the moment a hook is called.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PpuRegisters {
+    pub lcdc: u8,
+    pub stat: u8,
+    pub scy: u8,
+    pub scx: u8,
+    pub ly: u8,
+    pub lyc: u8,
+    pub bgp: u8,
+    pub obp0: u8,
+    pub obp1: u8,
+    pub wy: u8,
+    pub wx: u8,
+}
+
+impl PpuRegisters {
+    pub fn snapshot(memory_map: &MemoryMap) -> Self {
+        Self {
+            lcdc: memory_map.get_io(Io::LCDC),
+            stat: memory_map.get_io(Io::STAT),
+            scy: memory_map.get_io(Io::SCY),
+            scx: memory_map.get_io(Io::SCX),
+            ly: memory_map.get_io(Io::LY),
+            lyc: memory_map.get_io(Io::LYC),
+            bgp: memory_map.get_io(Io::BGP),
+            obp0: memory_map.get_io(Io::OBP0),
+            obp1: memory_map.get_io(Io::OBP1),
+            wy: memory_map.get_io(Io::WY),
+            wx: memory_map.get_io(Io::WX),
+        }
+    }
+}
+
+pub type ModeHook = Rc<RefCell<dyn FnMut(Mode, u8, &PpuRegisters)>>;
+pub type ScanlineHook = Rc<RefCell<dyn FnMut(u8, &PpuRegisters)>>;
+
+/*
+    Callbacks for inspecting the ppu state in the middle of a frame.
+    Register snapshots are only taken when a hook is set, so unused hooks cost a branch.
+    Hooks are shared with the clones of the ppu, a clone calls the same closures until its hooks are set or cleared.
+*/
+#[derive(Clone, Default)]
+pub struct PpuHooks {
+    pub on_mode_change: Option<ModeHook>,
+    pub on_scanline: Option<ScanlineHook>,
+}
+
+impl PpuHooks {
+    pub fn mode_changed(&mut self, mode: Mode, line: u8, memory_map: &MemoryMap) {
+        if let Some(hook) = &self.on_mode_change {
+            (hook.borrow_mut())(mode, line, &PpuRegisters::snapshot(memory_map));
+        }
+    }
+
+    pub fn scanline_started(&mut self, line: u8, memory_map: &MemoryMap) {
+        if let Some(hook) = &self.on_scanline {
+            (hook.borrow_mut())(line, &PpuRegisters::snapshot(memory_map));
+        }
+    }
+}
@@ -1,14 +1,23 @@
+mod hooks;
 mod pixel_fetcher;
 mod pixel_fifo;
+mod pixel_format;
 
 use arrayvec::ArrayVec;
+use std::{cell::RefCell, rc::Rc};
 use strum_macros::AsRefStr;
 
 use self::{
+    hooks::PpuHooks,
     pixel_fetcher::{PixelFetcher, PixelFetcherMode},
     pixel_fifo::PixelFifo,
 };
 
+pub use self::{
+    hooks::{ModeHook, PpuRegisters, ScanlineHook},
+    pixel_format::PixelFormat,
+};
+
 use super::memory_map::{Io, MemoryMap};
 
 pub const SCREEN_WIDTH: usize = 160;
@@ -41,6 +50,54 @@ impl Object {
     }
 }
 
+// Length of the modes in a scanline in dots, as they are reported by STAT.
+// Pixel transfer gets longer with the scrolling, window and object penalties and HBlank gets shorter.
+// STAT reports mode 0 from the start of the M-cycle in which the last pixel is pushed, up to 3 dots before pixel transfer ends.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct LineTiming {
+    pub oam_search: u16,
+    pub pixel_transfer: u16,
+    pub hblank: u16,
+}
+
+// Layers that are drawn to the screen buffer.
+// Hiding a layer is a debugging aid, it doesn't change LCDC or the ppu timing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayerOptions {
+    pub background: bool,
+    pub window: bool,
+    pub objects: bool,
+}
+
+impl Default for LayerOptions {
+    fn default() -> Self {
+        Self {
+            background: true,
+            window: true,
+            objects: true,
+        }
+    }
+}
+
+// Every layer rendered into its own buffer in the same format as the screen buffer.
+// Pixels that don't belong to a layer are transparent (0) in its buffer.
+#[derive(Clone)]
+pub struct LayerBuffers {
+    pub background: Box<[u32; SCREEN_WIDTH * SCREEN_HEIGHT]>,
+    pub window: Box<[u32; SCREEN_WIDTH * SCREEN_HEIGHT]>,
+    pub objects: Box<[u32; SCREEN_WIDTH * SCREEN_HEIGHT]>,
+}
+
+impl Default for LayerBuffers {
+    fn default() -> Self {
+        Self {
+            background: Box::new([0u32; SCREEN_WIDTH * SCREEN_HEIGHT]),
+            window: Box::new([0u32; SCREEN_WIDTH * SCREEN_HEIGHT]),
+            objects: Box::new([0u32; SCREEN_WIDTH * SCREEN_HEIGHT]),
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, AsRefStr)]
 pub enum Mode {
@@ -56,11 +113,17 @@ pub struct Ppu {
 
     mode: Mode,
 
+    // Internal STAT interrupt line.
+    stat_line: bool,
+
     enabled: bool,
     is_first_frame: bool,
 
+    // Number of frames finished since the start, counted at the VBlank entry.
+    frame_count: u64,
+
     pos_x: usize,
-    scroll_x: u8,
+    scroll_x: u8, // Scrolled pixels of the first tile that are left to be discarded.
 
     // The window keeps an internal line counter that’s functionally similar to LY, and increments alongside it.
     window_line_counter: u16,
@@ -72,8 +135,23 @@ pub struct Ppu {
 
     found_objects: ArrayVec<Object, 10>,
 
+    // Dots of the line where pixel transfer of the current line started and ended.
+    pixel_transfer_start: u32,
+    pixel_transfer_end: u32,
+    // LCDC value that is being written, disabling BG or OBJ is partly seen a dot before the write lands.
+    lcdc_write: Option<u8>,
+    line_timings: [LineTiming; SCREEN_HEIGHT],
+
+    hooks: PpuHooks,
+
     pub screen_buffer: Box<[u32; SCREEN_WIDTH * SCREEN_HEIGHT]>,
     pub color_shades: [u32; 4],
+    // Shades of the screen pixels from 0 (lightest) to 3 (darkest), they don't depend on color_shades.
+    pub shade_buffer: Box<[u8; SCREEN_WIDTH * SCREEN_HEIGHT]>,
+
+    pub layers: LayerOptions,
+    // Separate layer buffers are only rendered when they are set.
+    pub layer_buffers: Option<LayerBuffers>,
 }
 
 impl Ppu {
@@ -83,9 +161,13 @@ impl Ppu {
 
             mode: Mode::OamSearch,
 
+            stat_line: false,
+
             enabled: false,
             is_first_frame: true,
 
+            frame_count: 0,
+
             pos_x: 0,
             scroll_x: 0,
             window_line_counter: 0,
@@ -96,8 +178,19 @@ impl Ppu {
 
             found_objects: ArrayVec::new(),
 
+            pixel_transfer_start: 0,
+            pixel_transfer_end: 0,
+            lcdc_write: None,
+            line_timings: [LineTiming::default(); SCREEN_HEIGHT],
+
+            hooks: PpuHooks::default(),
+
             screen_buffer: Box::new([0u32; SCREEN_WIDTH * SCREEN_HEIGHT]),
             color_shades: [0xff0fbc9b, 0xff0fac8b, 0xff306230, 0xff0f380f],
+            shade_buffer: Box::new([0u8; SCREEN_WIDTH * SCREEN_HEIGHT]),
+
+            layers: LayerOptions::default(),
+            layer_buffers: None,
         }
     }
 
@@ -127,6 +220,8 @@ impl Ppu {
                     attributes: memory_map.ppu_get_oam(address + 3),
                 };
 
+                // Only Y position is checked. Objects that are off-screen horizontally are not drawn
+                // but they still count toward the 10 objects per line limit.
                 if object.is_visible(memory_map.get_io(Io::LY), memory_map.get_io(Io::LCDC)) {
                     self.found_objects.push(object);
 
@@ -136,9 +231,9 @@ impl Ppu {
                 }
             }
 
-            // TODO:
-            self.found_objects
-                .sort_by(|lhs, rhs| rhs.pos_x.cmp(&lhs.pos_x));
+            // Objects are fetched from the start of the list. The sort is stable, so when objects share the same X,
+            // the one that comes first in OAM is fetched first and stays on top.
+            self.found_objects.sort_by_key(|object| object.pos_x);
         }
 
         self.clock_cycles += dots;
@@ -159,8 +254,92 @@ impl Ppu {
                 incurs a 6- to 11-dot penalty.
         */
 
+        self.pixel_transfer_dots(memory_map, dots);
+
+        self.clock_cycles += dots;
+        self.update_mode(memory_map);
+    }
+
+    fn pixel_transfer_dots(&mut self, memory_map: &mut MemoryMap, dots: u32) {
+        for dot in 0..dots {
+            self.apply_ppu_io_write(memory_map, dot);
+
+            if self.pos_x < SCREEN_WIDTH + 8 {
+                self.pixel_transfer_dot(memory_map);
+
+                if self.pos_x == SCREEN_WIDTH + 8 {
+                    self.pixel_transfer_end = self.clock_cycles % PPU_ONE_LINE + dot + 1;
+                    self.end_line();
+                }
+            }
+        }
+    }
+
+    // Runs pixel transfer ahead on a copy of the fetcher and fifo state to see if the last pixel is pushed within the dots.
+    // Cpu writes that land in those dots are not known yet. Pixels drawn ahead are drawn again when the dots are run.
+    fn pixel_transfer_ends_within(&mut self, memory_map: &mut MemoryMap, dots: u32) -> bool {
+        // Every pixel takes at least a dot.
+        if self.pos_x + (dots as usize) < SCREEN_WIDTH + 8 {
+            return false;
+        }
+
+        let pos_x = self.pos_x;
+        let scroll_x = self.scroll_x;
+        let window_line_counter = self.window_line_counter;
+        let fifo = self.fifo;
+        let oam_fifo = self.oam_fifo;
+        let pixel_fetcher = self.pixel_fetcher.clone();
+        let found_objects = self.found_objects.clone();
+        let lcdc_write = self.lcdc_write.take();
+        let ppu_io_write = memory_map.ppu_io_write.take();
+
+        for _ in 0..dots {
+            if self.pos_x < SCREEN_WIDTH + 8 {
+                self.pixel_transfer_dot(memory_map);
+            }
+        }
+
+        let ends = self.pos_x == SCREEN_WIDTH + 8;
+
+        self.pos_x = pos_x;
+        self.scroll_x = scroll_x;
+        self.window_line_counter = window_line_counter;
+        self.fifo = fifo;
+        self.oam_fifo = oam_fifo;
+        self.pixel_fetcher = pixel_fetcher;
+        self.found_objects = found_objects;
+        self.lcdc_write = lcdc_write;
+        memory_map.ppu_io_write = ppu_io_write;
+
+        ends
+    }
+
+    // Called when the last pixel of a line is pushed.
+    fn end_line(&mut self) {
+        let line = ((self.clock_cycles % PPU_ONE_FRAME) / PPU_ONE_LINE) as usize;
+
+        // HBlank lasts until the end of the first M-cycle of the next line.
+        self.line_timings[line] = LineTiming {
+            oam_search: (self.pixel_transfer_start - 4) as u16,
+            pixel_transfer: (self.pixel_transfer_end - self.pixel_transfer_start) as u16,
+            hblank: (PPU_ONE_LINE + 4 - self.pixel_transfer_end) as u16,
+        };
+
+        if self.pixel_fetcher.window_started {
+            // Increment the window internal line counter.
+            self.window_line_counter += 1;
+        }
+    }
+
+    // Registers are sampled at the exact dot they are used, so cpu writes in the middle of a scanline are visible in it.
+    fn pixel_transfer_dot(&mut self, memory_map: &mut MemoryMap) {
         let lcdc = memory_map.get_io(Io::LCDC);
 
+        // Bits cleared by an LCDC write are seen a dot early by the object fetch and the first pixel of the line.
+        let early_lcdc = lcdc & self.lcdc_write.unwrap_or(0xFF);
+        let obj_fetch_enable = early_lcdc & 0x2 != 0;
+        let lcdc = if self.pos_x == 8 { early_lcdc } else { lcdc };
+
         let bg_w_enable = lcdc & 0x1 != 0; // LCDC.0 — BG and Window enable/priority
         let obj_enable = lcdc & 0x2 != 0; // LCDC.1 — OBJ enable
         let window_enable = bg_w_enable && lcdc & 0x20 != 0; // LCDC.5 — Window enable
@@ -171,99 +350,197 @@ impl Ppu {
         let ly = memory_map.get_io(Io::LY);
         let scx = memory_map.get_io(Io::SCX);
 
-        let mut fetcher_cycles = 0;
-
-        for _ in 0..dots {
-            if self.pos_x >= SCREEN_WIDTH + 8 {
-                break;
+        let wx_match = ly >= wy && self.pos_x == wx as usize + 1;
+        let wx_written =
+            matches!(memory_map.ppu_io_write, Some((address, _)) if address == Io::WX as u16);
+        // Before the window started on the line, it also starts a pixel after WX matched, unless WX is being written.
+        let late_wx_match = !self.pixel_fetcher.window_started
+            && !wx_written
+            && ly >= wy
+            && self.pos_x == wx as usize + 2;
+
+        if window_enable && !self.pixel_fetcher.is_window && (wx_match || late_wx_match) {
+            // Start of the window.
+            self.fifo = PixelFifo::new(); // Flush the fifo.
+            self.pixel_fetcher.restart();
+            self.pixel_fetcher.pos_x = 0;
+            self.pixel_fetcher.is_window = true;
+
+            // The window row advances every time the window starts again on the same line.
+            if self.pixel_fetcher.window_started {
+                self.window_line_counter += 1;
             }
+            self.pixel_fetcher.window_started = true;
 
-            if window_enable && ly >= wy && self.pos_x == wx as usize + 1 {
-                // Start of the window.
-                if !self.pixel_fetcher.is_window {
-                    self.fifo = PixelFifo::new(); // Flush the fifo.
-                    self.pixel_fetcher.mode = PixelFetcherMode::GetTile;
-                    self.pixel_fetcher.pos_x = 0;
-                }
-                self.pixel_fetcher.is_window = true;
+            // With WX=0 the window fetch starts a dot later when SCX isn't a multiple of 8.
+            if wx == 0 && scx & 0x7 != 0 {
+                return;
             }
+        } else if wx_match
+            && self.fifo.pixel_count == 0
+            && matches!(self.pixel_fetcher.mode, PixelFetcherMode::Push(_))
+            && self.pixel_fetcher.pos_x > 0
+        {
+            // On DMG, WX matching at the start of a tile inserts a pixel with color 0, even with the window disabled.
+            self.fifo.push_front(PixelFifo::WINDOW_PIXEL);
+        }
 
-            // Pixel fetcher cycle takes 2 dots most of the time
-            // but there is a condition that it can take 1 dot.
-            if fetcher_cycles < dots {
-                fetcher_cycles += self.pixel_fetcher.cycle(
-                    memory_map,
-                    &mut self.fifo,
-                    &mut self.oam_fifo,
-                    self.window_line_counter,
-                );
-            }
+        self.pixel_fetcher.cycle(
+            memory_map,
+            &mut self.fifo,
+            &mut self.oam_fifo,
+            self.window_line_counter,
+        );
 
-            // Check the last element since the found_objects is sorted in decreasing order.
-            if let Some(object) = self.found_objects.last() {
-                if self.pos_x == object.pos_x as usize {
-                    // Wait until the pixel fetcher finishes tile fetching.
-                    if self.pixel_fetcher.mode != PixelFetcherMode::GetTile {
-                        self.pixel_fetcher.hit_object = true;
-                        continue;
-                    }
+        // Disabling objects stops the object fetch, the background fetch continues where it was paused.
+        if !obj_fetch_enable && self.pixel_fetcher.fetching_object.is_some() {
+            self.pixel_fetcher.abort_object();
+        }
+
+        // Fifo popping is paused when an object is fetched.
+        // This kind of actions makes the pixel transfer mode longer.
+        if self.pixel_fetcher.fetching_object.is_some() || self.fifo.pixel_count == 0 {
+            return;
+        }
+
+        // Objects reached while objects are disabled are skipped.
+        while !obj_fetch_enable
+            && self
+                .found_objects
+                .first()
+                .is_some_and(|object| self.pos_x == object.pos_x as usize)
+        {
+            self.found_objects.remove(0);
+        }
 
+        // Check the first element since the found_objects is sorted in increasing order.
+        if let Some(&object) = self.found_objects.first() {
+            if self.pos_x == object.pos_x as usize {
+                // Wait until the pixel fetcher finishes fetching the background tile.
+                if self.pixel_fetcher.is_tile_fetched() {
                     // Remove the object from the self.found_objects so it doesnt get rendered again.
-                    self.pixel_fetcher.fetching_object = self.found_objects.pop();
+                    self.found_objects.remove(0);
+                    self.pixel_fetcher.fetch_object(object);
                 }
-            }
 
-            // Fifo popping is paused when an object is fetched.
-            // This kind of actions makes the pixel transfer mode longer.
-            if self.pixel_fetcher.fetching_object.is_some() {
-                continue;
+                return;
             }
+        }
 
-            // Popping the pixel fifo is always 1 dot.
-            if self.fifo.pixel_count > 8 {
-                let (mut color, color_index, _) = self.fifo.pop(memory_map, &self.color_shades);
+        // SCX % 8 is sampled with the first pixel of the line, later writes don't change the discarded pixels.
+        if self.pos_x == 0 {
+            self.scroll_x = scx % 8;
+        }
 
-                // Discard the first scrolled pixels that are smaller than a tile.
-                // This creates a smooth scrolling effect.
-                if self.scroll_x < scx % 8 {
-                    self.scroll_x += 1;
-                    continue;
-                }
+        // Popping the pixel fifo is always 1 dot.
+        let (mut shade, mut color_index, _) = self.fifo.pop(memory_map);
 
-                if self.oam_fifo.pixel_count > 0 {
-                    let (object_color, object_color_index, priority) =
-                        self.oam_fifo.pop(memory_map, &self.color_shades);
+        // Discard the first scrolled pixels that are smaller than a tile.
+        // This creates a smooth scrolling effect and pauses the rendering for SCX % 8 dots.
+        if self.pos_x == 8 && self.scroll_x > 0 {
+            self.scroll_x -= 1;
+            return;
+        }
 
-                    if obj_enable {
-                        if !bg_w_enable
-                            || (object_color_index != 0 && (priority == 0 || color_index == 0))
-                        {
-                            color = object_color;
-                        }
-                    }
-                } else if !bg_w_enable {
-                    color = self.color_shades[0]; // Background and window disabled. Render a white color.
-                }
+        // The fifo is flushed at the start of the window, all of its pixels are window pixels after that.
+        let is_window = self.pixel_fetcher.is_window;
+        let background_shade = shade;
 
-                /*
-                    From pandocs:
-                    When re-enabling the LCD, the PPU will immediately start drawing again,
-                        but the screen will stay blank during the first frame.
-                */
-                if !self.is_first_frame && self.pos_x >= 8 {
-                    self.screen_buffer[(self.pos_x - 8) + ly as usize * SCREEN_WIDTH] = color;
-                }
+        let layer_visible = if is_window {
+            self.layers.window
+        } else {
+            self.layers.background
+        };
+        if !layer_visible {
+            // Hidden layers are drawn as the lightest color, objects are visible above them.
+            shade = 0;
+            color_index = 0;
+        }
+
+        let mut object_pixel = None;
+
+        if self.oam_fifo.pixel_count > 0 {
+            let (object_shade, object_color_index, priority) = self.oam_fifo.pop(memory_map);
 
-                self.pos_x += 1;
+            // Layer buffers have the hidden layers too.
+            if obj_enable && object_color_index != 0 {
+                object_pixel = Some(object_shade);
             }
+
+            if obj_enable
+                && self.layers.objects
+                && (!bg_w_enable
+                    || (object_color_index != 0 && (priority == 0 || color_index == 0)))
+            {
+                shade = object_shade;
+            }
+        } else if !bg_w_enable {
+            shade = 0; // Background and window disabled. Render a white color.
         }
 
-        self.clock_cycles += dots;
-        self.update_mode(memory_map);
+        /*
+            From pandocs:
+            When re-enabling the LCD, the PPU will immediately start drawing again,
+                but the screen will stay blank during the first frame.
+        */
+        if !self.is_first_frame && self.pos_x >= 8 {
+            let index = (self.pos_x - 8) + ly as usize * SCREEN_WIDTH;
+            self.screen_buffer[index] = self.color_shades[shade as usize];
+            self.shade_buffer[index] = shade;
+
+            if let Some(layer_buffers) = &mut self.layer_buffers {
+                let background_color = if bg_w_enable {
+                    self.color_shades[background_shade as usize]
+                } else {
+                    0
+                };
+
+                layer_buffers.background[index] = if is_window { 0 } else { background_color };
+                layer_buffers.window[index] = if is_window { background_color } else { 0 };
+                layer_buffers.objects[index] =
+                    object_pixel.map_or(0, |shade| self.color_shades[shade as usize]);
+            }
+        }
+
+        self.pos_x += 1;
+    }
+
+    /*
+        Cpu writes to the ppu registers land in the middle of the M-cycle.
+        On DMG, palettes are the old and the new value ORed together for one dot before the new value is seen.
+        The window start compares the old WX for one more dot.
+    */
+    fn apply_ppu_io_write(&mut self, memory_map: &mut MemoryMap, dot: u32) {
+        self.lcdc_write = None;
+
+        let Some((address, value)) = memory_map.ppu_io_write else {
+            return;
+        };
+
+        let is_palette =
+            address == Io::BGP as _ || address == Io::OBP0 as _ || address == Io::OBP1 as _;
+        let is_sampled = is_palette
+            || address == Io::LCDC as _
+            || address == Io::SCY as _
+            || address == Io::SCX as _
+            || address == Io::WY as _;
+
+        match dot {
+            1 if is_palette => memory_map.set(address, memory_map.get(address) | value),
+            1 if address == Io::LCDC as _ => self.lcdc_write = Some(value),
+            2 if is_sampled => memory_map.set(address, value),
+            3 if address == Io::WX as _ => memory_map.set(address, value),
+            _ => {}
+        }
     }
 
     fn hblank(&mut self, memory_map: &mut MemoryMap, dots: u32) {
+        if self.pos_x < SCREEN_WIDTH + 8 {
+            self.pixel_transfer_dots(memory_map, dots);
+        }
+
         self.clock_cycles += dots;
+
         self.update_mode(memory_map);
     }
 
@@ -273,72 +550,47 @@ impl Ppu {
     }
 
     fn update_mode(&mut self, memory_map: &mut MemoryMap) {
+        // Clock is kept in the current frame on every dot, so it doesn't overflow in long runs.
+        self.clock_cycles %= PPU_ONE_FRAME;
+
         let line_remainder = self.clock_cycles % PPU_ONE_LINE;
         let line = ((self.clock_cycles % PPU_ONE_FRAME) / PPU_ONE_LINE) as u8;
 
-        // Update LY and LYC.
-        if line_remainder == 0 {
-            memory_map.set_io(Io::LY, line);
-
-            let lyc = memory_map.get_io(Io::LYC);
-
-            // Set the coincident flag.
-            memory_map.set_io(
-                Io::STAT,
-                (memory_map.get_io(Io::STAT) & 0xFB) | ((lyc == line) as u8) << 2,
-            );
-
-            if lyc == line {
-                // Cause interrupt in case of Coincidence interrupt is set.
-                if memory_map.get_io(Io::STAT) & 0x40 != 0 {
-                    // Request a LCD STAT interrupt.
-                    memory_map.set_io(Io::IF, memory_map.get_io(Io::IF) | 0x2);
-                }
-            }
-        }
-
         memory_map.current_oam_row = if line_remainder == 0 { Some(1) } else { None };
 
         let mode = match self.mode {
             Mode::OamSearch => {
                 memory_map.current_oam_row = Some(line_remainder as u16 / 4 + 1);
 
-                if line_remainder >= 80 {
+                // Mode 2 starts after the first M-cycle of the line and lasts 80 dots.
+                if line_remainder >= 84 {
                     self.pos_x = 0;
                     self.scroll_x = 0;
                     self.fifo = PixelFifo::new();
                     self.oam_fifo = PixelFifo::new();
                     self.pixel_fetcher = PixelFetcher::new();
 
-                    // Push a background tile that will never be on screen.
-                    // This is done for rendering objects with x < 8.
-                    self.fifo.push(0, PixelFifo::BACKGROUND_PIXEL, 0);
-
                     Mode::PixelTransfer
                 } else {
                     return;
                 }
             }
             Mode::PixelTransfer => {
-                // TODO:
-                if self.pos_x >= SCREEN_WIDTH + 8 {
-                    if self.pixel_fetcher.is_window {
-                        // Increment the window internal line counter.
-                        self.window_line_counter += 1;
-                    }
-
+                // Pixel transfer ends when the last pixel is pushed to the screen, its length depends on fetcher stalls.
+                // HBlank starts with the M-cycle that pushes the last pixel, the pixels that are left are pushed in it.
+                if self.pos_x >= SCREEN_WIDTH + 8 || self.pixel_transfer_ends_within(memory_map, 3)
+                {
                     Mode::HBlank
                 } else {
                     return;
                 }
             }
             Mode::HBlank => {
-                if line_remainder == 0 {
-                    if line == 144 {
-                        Mode::VBlank
-                    } else {
-                        Mode::OamSearch
-                    }
+                // VBlank starts after the first M-cycle of its first line, like mode 2 does.
+                if line == 144 && line_remainder == 4 {
+                    Mode::VBlank
+                } else if line != 144 && line_remainder == 0 {
+                    Mode::OamSearch
                 } else {
                     return;
                 }
@@ -357,27 +609,154 @@ impl Ppu {
 
         // if mode == Mode::HBlank { println!("{}", (self.clock_cycles % 456) - 80); }
 
-        // Update flags and io registers.
-        let stat = memory_map.get_io(Io::STAT);
+        // Request VBlank interrupt when mode changes to VBlank
+        if mode == Mode::VBlank {
+            memory_map.set_io(Io::IF, memory_map.get_io(Io::IF) | 0x1);
+            self.frame_count += 1;
+        }
+
+        self.mode = mode;
+
+        if mode == Mode::PixelTransfer {
+            self.pixel_transfer_start = line_remainder;
+        }
+
+        self.hooks.mode_changed(mode, line, memory_map);
+        if mode == Mode::PixelTransfer {
+            // Registers that are sampled at the start of the pixel transfer are used for the entire line.
+            self.hooks.scanline_started(line, memory_map);
+        }
+    }
+
+    fn update_stat_mode(&mut self, memory_map: &mut MemoryMap) {
+        let line_remainder = self.clock_cycles % PPU_ONE_LINE;
+        let line = ((self.clock_cycles % PPU_ONE_FRAME) / PPU_ONE_LINE) as u8;
+
+        /*
+            Mode 2 becomes visible after the first M-cycle of a line, until then STAT reports mode 0.
+            When the LCD is turned on, ppu starts 4 dots into the first line and doesn't do OAM search in it,
+                so STAT reports mode 0 instead of mode 2 and OAM/VRAM stay accessible until mode 3.
+        */
+        let is_lcd_on_line = self.is_first_frame && line == 0;
+
+        let access_mode = match self.mode {
+            Mode::OamSearch | Mode::PixelTransfer if is_lcd_on_line && line_remainder < 84 => {
+                Mode::HBlank
+            }
+            mode => mode,
+        };
+
+        /*
+            Reads are blocked an M-cycle before STAT reports the mode:
+            OAM from the start of the line and VRAM from the last M-cycle of OAM search.
+        */
+        memory_map.ppu_mode = match access_mode {
+            Mode::OamSearch if line_remainder >= 80 => Mode::PixelTransfer,
+            mode => mode,
+        };
+
+        let mode = match access_mode {
+            Mode::OamSearch if line_remainder < 4 => Mode::HBlank,
+            mode => mode,
+        };
+
+        /*
+            Writes are blocked by the mode STAT reports,
+            except that OAM writes are possible again in the last M-cycle of OAM search.
+        */
+        memory_map.ppu_write_mode = match mode {
+            Mode::OamSearch if line_remainder >= 80 => Mode::HBlank,
+            mode => mode,
+        };
 
         // "The two lower STAT bits show the current status of the LCD controller."
-        // Change the mode in the STAT register.
-        memory_map.set_io(Io::STAT, (stat & 0xFC) | (mode as u8));
+        memory_map.set_io(
+            Io::STAT,
+            (memory_map.get_io(Io::STAT) & 0xFC) | (mode as u8),
+        );
+    }
 
-        // Request a LDC STAT interrupt in case of interrupt bits set.
-        if (mode == Mode::OamSearch && stat & 0x20 != 0)
-            || (mode == Mode::VBlank && stat & 0x10 != 0)
-            || (mode == Mode::HBlank && stat & 0x08 != 0)
-        {
+    fn update_ly(&mut self, memory_map: &mut MemoryMap) {
+        let line_remainder = self.clock_cycles % PPU_ONE_LINE;
+        let line = ((self.clock_cycles % PPU_ONE_FRAME) / PPU_ONE_LINE) as u8;
+
+        /*
+            LY=153 quirk:
+            LY reads 153 only in the first M-cycle of the last line and 0 for the rest of it.
+            LY=LYC comparison is done with the LY of the previous M-cycle,
+                so the coincidence flag is clear in the first M-cycle of a line.
+            LY=0 is compared an M-cycle later in the last line, the flag is clear for an M-cycle in between.
+        */
+        let (ly, ly_compare) = match (line, line_remainder) {
+            (0, _) => (0, Some(0)),
+            (153, 4) => (0, Some(153)),
+            (153, 8) => (0, None),
+            (153, 12..) => (0, Some(0)),
+            (_, 0) => (line, None),
+            _ => (line, Some(line)),
+        };
+
+        memory_map.set_io(Io::LY, ly);
+
+        let lyc = memory_map.get_io(Io::LYC);
+
+        // Set the coincidence flag.
+        memory_map.set_io(
+            Io::STAT,
+            (memory_map.get_io(Io::STAT) & 0xFB) | ((ly_compare == Some(lyc)) as u8) << 2,
+        );
+    }
+
+    /*
+        Mode 0, 1, 2 and LY=LYC interrupt sources are ORed into a single STAT interrupt line.
+        LCD STAT interrupt is requested only on the rising edge of this line,
+            so a source can't cause an interrupt while another one is keeping the line high(STAT blocking).
+    */
+    fn update_stat_line(&mut self, memory_map: &mut MemoryMap) {
+        let stat = memory_map.get_io(Io::STAT);
+
+        // DMG STAT write bug: Writing to STAT behaves as if all interrupt sources were enabled for an M-cycle.
+        let sources = if std::mem::take(&mut memory_map.stat_written) {
+            0x78
+        } else {
+            stat
+        };
+
+        // Mode 2 interrupt source is also triggered at the start of the VBlank.
+        let is_vblank_start = self.clock_cycles % PPU_ONE_FRAME == 144 * PPU_ONE_LINE + 4;
+
+        // Mode 2 interrupt source of the first line goes high an M-cycle later than the other lines.
+        let is_oam_search = self.mode == Mode::OamSearch && self.clock_cycles % PPU_ONE_FRAME >= 4;
+
+        // Mode 0 interrupt source goes high an M-cycle before STAT reports mode 0, while the last pixels are shifted out.
+        let is_hblank = self.mode == Mode::HBlank
+            || (self.mode == Mode::PixelTransfer && self.pos_x >= SCREEN_WIDTH + 4);
+
+        let stat_line = (sources & 0x08 != 0 && is_hblank)
+            || (sources & 0x10 != 0 && self.mode == Mode::VBlank)
+            || (sources & 0x20 != 0 && (is_oam_search || is_vblank_start))
+            || (sources & 0x40 != 0 && stat & 0x04 != 0);
+
+        if stat_line && !self.stat_line {
+            // Request a LCD STAT interrupt.
             memory_map.set_io(Io::IF, memory_map.get_io(Io::IF) | 0x2);
         }
 
-        // Request VBlank interrupt when mode changes to VBlank
-        if mode == Mode::VBlank {
-            memory_map.set_io(Io::IF, memory_map.get_io(Io::IF) | 0x1);
+        self.stat_line = stat_line;
+    }
+
+    // Updates the STAT mode, LY, the coincidence flag and the STAT interrupt line.
+    // Must also be called after cpu writes to LCDC, STAT or LYC since they affect the STAT interrupt line immediately.
+    pub fn update_stat(&mut self, memory_map: &mut MemoryMap) {
+        if memory_map.get_io(Io::LCDC) & 0x80 == 0 {
+            // STAT interrupt line isn't updated while LCD is off, so STAT writes can't cause an interrupt.
+            memory_map.stat_written = false;
+            return;
         }
 
-        self.mode = mode;
+        self.update_stat_mode(memory_map);
+        self.update_ly(memory_map);
+        self.update_stat_line(memory_map);
     }
 
     pub fn cycle(&mut self, memory_map: &mut MemoryMap, dots: u32) {
@@ -385,18 +764,24 @@ impl Ppu {
 
         if lcdc & 0x80 == 0 {
             // LCDC is disabled.
+            memory_map.stat_written = false;
 
             if self.enabled {
                 // Ppu renders an empty screen when LCD is turned off.
-                // Clear the screen.
-                self.screen_buffer.fill(self.color_shades[0]);
+                self.clear_screen();
 
                 // Reset ppu flags.
                 memory_map.set_io(Io::LY, 0);
-                memory_map.set_io(Io::STAT, 0);
+                // Coincidence flag keeps its value while LCD is off.
+                memory_map.set_io(Io::STAT, memory_map.get_io(Io::STAT) & 0xFC);
                 self.clock_cycles = dots;
                 self.mode = Mode::OamSearch;
 
+                // VRAM and OAM are accessible as soon as LCD is turned off.
+                memory_map.ppu_mode = Mode::HBlank;
+                memory_map.ppu_write_mode = Mode::HBlank;
+                memory_map.current_oam_row = None;
+
                 self.enabled = false;
                 self.is_first_frame = true;
             }
@@ -412,6 +797,18 @@ impl Ppu {
             Mode::HBlank => self.hblank(memory_map, dots),
             Mode::VBlank => self.vblank(memory_map, dots),
         };
+
+        self.update_stat(memory_map);
+    }
+
+    pub fn clear_screen(&mut self) {
+        self.screen_buffer.fill(self.color_shades[0]);
+        self.shade_buffer.fill(0);
+    }
+
+    // Mode timings of a visible scanline in the last frame it was drawn.
+    pub fn line_timing(&self, line: u8) -> Option<LineTiming> {
+        self.line_timings.get(line as usize).copied()
     }
 
     #[allow(dead_code)]
@@ -424,6 +821,32 @@ impl Ppu {
         self.is_first_frame
     }
 
+    // Sets a closure that is called with the new mode and LY whenever the ppu changes its mode.
+    // Clones of the ppu share the closure.
+    pub fn set_mode_hook(&mut self, hook: impl FnMut(Mode, u8, &PpuRegisters) + 'static) {
+        self.hooks.on_mode_change = Some(Rc::new(RefCell::new(hook)));
+    }
+
+    // Sets a closure that is called with LY at the start of the pixel transfer of every visible line.
+    // Clones of the ppu share the closure.
+    pub fn set_scanline_hook(&mut self, hook: impl FnMut(u8, &PpuRegisters) + 'static) {
+        self.hooks.on_scanline = Some(Rc::new(RefCell::new(hook)));
+    }
+
+    pub fn clear_hooks(&mut self) {
+        self.hooks = PpuHooks::default();
+    }
+
+    // Number of frames that are finished, screen buffer holds a complete frame when it changes.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    // LCD doesn't show anything while it's off and during the first frame after turning it on.
+    pub fn is_display_blank(&self) -> bool {
+        !self.enabled || self.is_first_frame
+    }
+
     pub fn get_color_pallete(color_shades: &[u32; 4], pallete_index: u8) -> [u32; 4] {
         [
             color_shades[(pallete_index & 0x3) as usize], // White
@@ -2,11 +2,11 @@ use super::*;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum PixelFetcherMode {
-    GetTile,              // 2 dot
-    GetTileLow(u8),       // 2 dot
-    GetTileHigh(u16, u8), // 2 dot
-    Sleep((u8, u8)),      // 2 dot
-    Push((u8, u8)),       // 1 dot until success
+    GetTile,             // 2 dot
+    GetTileLow(u8),      // 2 dot
+    GetTileHigh(u8, u8), // 2 dot
+    Sleep((u8, u8)),     // 2 dot
+    Push((u8, u8)),      // 1 dot until success
 }
 
 #[derive(Clone)]
@@ -14,11 +14,14 @@ pub struct PixelFetcher {
     pub pos_x: u16,
 
     pub fetching_object: Option<Object>,
-    pub hit_object: bool, // This is must be set to true in case of an sprite hit.
+    paused_mode: Option<(PixelFetcherMode, bool)>, // Background fetch that is paused while fetching an object.
 
     pub is_window: bool,
+    pub window_started: bool, // Set once the window started on this line, it starts again after being disabled.
+    is_first_tile: bool,
 
     pub mode: PixelFetcherMode,
+    second_dot: bool, // Set while waiting for the second dot of a 2 dot step.
 }
 
 impl PixelFetcher {
@@ -27,20 +30,60 @@ impl PixelFetcher {
             pos_x: 0,
 
             fetching_object: None,
-            hit_object: false,
+            paused_mode: None,
             is_window: false,
+            window_started: false,
+            is_first_tile: true,
 
-            mode: PixelFetcherMode::GetTile,
+            // Pixels of the first tile are thrown away, its fetch starts at the high byte.
+            mode: PixelFetcherMode::GetTileHigh(0, 0),
+            second_dot: false,
         }
     }
 
+    // Throws away the tile that is being fetched and starts fetching from the beginning.
+    pub fn restart(&mut self) {
+        self.mode = PixelFetcherMode::GetTile;
+        self.second_dot = false;
+    }
+
+    // Background tile data is fetched and waiting to be pushed, so an object can be fetched without delaying it.
+    pub fn is_tile_fetched(&self) -> bool {
+        matches!(
+            self.mode,
+            PixelFetcherMode::Sleep(_) | PixelFetcherMode::Push(_)
+        )
+    }
+
+    // Pauses the background fetch and starts fetching the object. Background fetch continues after the object is pushed.
+    pub fn fetch_object(&mut self, object: Object) {
+        self.paused_mode = Some((self.mode, self.second_dot));
+        self.fetching_object = Some(object);
+        self.mode = PixelFetcherMode::GetTile;
+        self.second_dot = false;
+    }
+
+    // Stops the object fetch without pushing the object and continues the paused background fetch.
+    pub fn abort_object(&mut self) {
+        self.fetching_object = None;
+        (self.mode, self.second_dot) = self.paused_mode.take().unwrap();
+    }
+
     pub fn cycle(
         &mut self,
         memory_map: &MemoryMap,
         fifo: &mut PixelFifo,
         oam_fifo: &mut PixelFifo,
         window_internal_line_counter: u16,
-    ) -> u32 {
+    ) {
+        // Fetcher steps take 2 dots except pushing to the fifo. Work is done in the second dot.
+        if !matches!(self.mode, PixelFetcherMode::Push(_)) && !self.second_dot {
+            self.second_dot = true;
+            return;
+        }
+
+        self.second_dot = false;
+
         let lcdc = memory_map.get_io(Io::LCDC);
         let ly = memory_map.get_io(Io::LY) as u16;
 
@@ -58,16 +101,69 @@ impl PixelFetcher {
         // Bit 2 - OBJ (Sprite) Size (0=8x8, 1=8x16)
         let object_height = if lcdc & 0x4 == 0 { 8 } else { 16 };
 
+        let fetching_object = self.fetching_object;
+        let is_window = self.is_window;
+
+        // Address of the low byte of the current row of a tile.
+        let tile_data_address = |tile_map_value: u8| {
+            // Check tile_data_start
+            // Vertical Flip
+
+            let mut tile_map_value = tile_map_value as i32;
+            let mut tile_y; // Y location of the current tile.
+            let tile_data_start;
+
+            if let Some(object) = fetching_object {
+                // Only the row bits of the current object size are used, a size change after the OAM scan is visible.
+                tile_y = (ly as i32 - (object.pos_y as i32 - 16)) & (object_height - 1);
+
+                // 8x16 objects ignore bit 0 of the tile index, the top tile is even and the bottom tile is odd.
+                if object_height == 16 {
+                    tile_map_value &= 0xFE;
+                }
+
+                if object.attributes & 0x40 != 0 {
+                    // Vertical flip is done over the whole object, 8x16 objects swap their two tiles too.
+                    tile_y = object_height - tile_y - 1;
+                }
+
+                tile_data_start = 0x8000;
+            } else {
+                if lcdc & 0x10 == 0 {
+                    // Convert tile_map_value to signed value.
+                    tile_map_value = ((tile_map_value as u8) as i8) as i32;
+                }
+
+                let pos_y = if is_window {
+                    window_internal_line_counter
+                } else {
+                    ly + scy
+                };
+
+                tile_y = ((pos_y % 256) % 8) as i32;
+                tile_data_start = bg_w_tile_data_start;
+            }
+
+            (tile_data_start + tile_map_value * 16 + tile_y * 2) as u16
+        };
+
+        let mut pushed_tile = false;
+
         self.mode = match self.mode {
             PixelFetcherMode::GetTile => {
                 let tile_map_value = if let Some(object) = self.fetching_object {
                     object.tile_index
                 } else {
+                    // Disabling the window switches back to the background at the next tile, the current tile stays.
+                    if lcdc & 0x20 == 0 {
+                        self.is_window = false;
+                    }
+
                     if self.is_window {
                         memory_map.ppu_get_vram(
                             w_tile_map_start
                                 + ((self.pos_x / 8) & 0x1F)
-                                + (window_internal_line_counter / 8) * 32,
+                                + ((window_internal_line_counter / 8) & 0x1F) * 32,
                         )
                     } else {
                         let scx = memory_map.get_io(Io::SCX) as u16;
@@ -84,48 +180,22 @@ impl PixelFetcher {
                 PixelFetcherMode::GetTileLow(tile_map_value)
             }
             PixelFetcherMode::GetTileLow(tile_map_value) => {
-                // Check tile_data_start
-                // Vertical Flip
-                // Pass tile_low to next mode
-
-                let mut tile_map_value = tile_map_value as i32;
-                let mut tile_y; // Y location of the current tile.
-                let tile_data_start;
-
-                if let Some(object) = self.fetching_object {
-                    tile_y = ly as i32 - (object.pos_y as i32 - 16);
+                let tile_low = memory_map.ppu_get_vram(tile_data_address(tile_map_value));
 
-                    if object_height == 16 {
-                        tile_map_value &= 0xFE;
-                    }
+                PixelFetcherMode::GetTileHigh(tile_map_value, tile_low)
+            }
+            PixelFetcherMode::GetTileHigh(tile_map_value, tile_low) => {
+                // Tile data address is calculated again, LCDC changes between the two fetches are visible.
+                let tile_high = memory_map.ppu_get_vram(tile_data_address(tile_map_value) + 1);
 
-                    if object.attributes & 0x40 != 0 {
-                        // Vertical flip
-                        tile_y = object_height as i32 - tile_y - 1;
-                    }
+                // The fifo is flushed at the start of the window, its first tile is pushed without sleeping.
+                let is_window_start = self.is_window && fifo.pixel_count == 0;
 
-                    tile_data_start = 0x8000;
+                if self.fetching_object.is_some() || is_window_start {
+                    PixelFetcherMode::Push((tile_low, tile_high))
                 } else {
-                    if lcdc & 0x10 == 0 {
-                        // Convert tile_map_value to signed value.
-                        tile_map_value = ((tile_map_value as u8) as i8) as i32;
-                    }
-
-                    let pos_y = if self.is_window { ly } else { ly + scy };
-
-                    tile_y = ((pos_y % 256) % 8) as i32;
-                    tile_data_start = bg_w_tile_data_start;
+                    PixelFetcherMode::Sleep((tile_low, tile_high))
                 }
-
-                let tile_index = (tile_data_start + tile_map_value * 16 + tile_y * 2) as u16;
-                let tile_low = memory_map.ppu_get_vram(tile_index);
-
-                PixelFetcherMode::GetTileHigh(tile_index, tile_low)
-            }
-            PixelFetcherMode::GetTileHigh(tile_index, tile_low) => {
-                let tile_high = memory_map.ppu_get_vram(tile_index + 1);
-
-                PixelFetcherMode::Sleep((tile_low, tile_high))
             }
             PixelFetcherMode::Sleep(tile) => PixelFetcherMode::Push(tile),
             PixelFetcherMode::Push((tile_low, tile_high)) => {
@@ -133,17 +203,9 @@ impl PixelFetcher {
                 // Only pushed if fifo is half empty.
                 // If horizontal flip is set just push LSB instead of MSB.
 
-                // In case of an sprite hit PPU must wait until pixel fetcher reaches PixelFetcherMode::Push.
-                // But any tiles fetched in this process will go away and must be computed again.
-                if self.hit_object {
-                    self.hit_object = false;
-                    self.mode = PixelFetcherMode::GetTile;
-                    return 1;
-                }
-
                 // Wait until fifo is empty if currently fetching background or window tile.
-                if self.fetching_object.is_none() && fifo.pixel_count > 8 {
-                    return 1;
+                if self.fetching_object.is_none() && fifo.pixel_count > 0 {
+                    return;
                 }
 
                 let mut tile = 0;
@@ -174,6 +236,11 @@ impl PixelFetcher {
 
                     // Push transparent pixels with lowest priority.
                     oam_fifo.push(tile, source, (object.attributes >> 7) as u16);
+
+                    // Continue the paused background fetch.
+                    self.fetching_object = None;
+                    (self.mode, self.second_dot) = self.paused_mode.take().unwrap();
+                    return;
                 } else {
                     let source = if self.is_window {
                         PixelFifo::WINDOW_PIXEL
@@ -182,21 +249,29 @@ impl PixelFetcher {
                     };
 
                     fifo.push(tile, source, 0);
-                    self.pos_x += 8;
-                }
+                    pushed_tile = true;
 
-                self.fetching_object = None;
+                    // First tile of a scanline is fetched twice. The first one is shifted out on the left of the screen,
+                    // where objects with x < 8 are rendered.
+                    if self.is_first_tile {
+                        self.is_first_tile = false;
+                    } else {
+                        self.pos_x += 8;
+                    }
+                }
 
                 PixelFetcherMode::GetTile
             }
         };
 
-        // If the mode is GetTile end of the above statement
-        // this means it was the Push mode so it has 1 dot length.
-        if self.mode == PixelFetcherMode::GetTile {
-            1
-        } else {
-            2
+        // Pushing a background or window tile doesn't take a dot of its own,
+        // fetching of the next tile starts in the same dot.
+        // Objects don't sleep, they are pushed in the same dot as their high byte is fetched.
+        let is_object_fetched =
+            self.fetching_object.is_some() && matches!(self.mode, PixelFetcherMode::Push(_));
+
+        if pushed_tile || is_object_fetched {
+            self.cycle(memory_map, fifo, oam_fifo, window_internal_line_counter);
         }
     }
 }
@@ -1,7 +1,4 @@
-use crate::{
-    memory_map::{Io, MemoryMap},
-    ppu::Ppu,
-};
+use crate::memory_map::{Io, MemoryMap};
 
 /*
     There are two pixel FIFOs.
@@ -31,7 +28,8 @@ impl PixelFifo {
         }
     }
 
-    pub fn pop(&mut self, memory_map: &MemoryMap, color_palette: &[u32; 4]) -> (u32, u16, u16) {
+    // Returns the shade of the pixel after the palette is applied, its color index and its background priority.
+    pub fn pop(&mut self, memory_map: &MemoryMap) -> (u8, u16, u16) {
         // TODO:
         assert!(self.pixel_count >= 0);
 
@@ -52,9 +50,17 @@ impl PixelFifo {
             _ => unreachable!(),
         });
 
-        let pallete = Ppu::get_color_pallete(color_palette, pallete_index);
+        let shade = (pallete_index >> (color * 2)) & 0x3;
 
-        (pallete[color as usize], color as u16, priority)
+        (shade, color as u16, priority)
+    }
+
+    // Inserts a pixel with color 0 in front of the fifo.
+    pub fn push_front(&mut self, source: u32) {
+        self.color_values <<= 2;
+        self.pixel_sources = (self.pixel_sources << 2) | source;
+        self.background_priority <<= 1;
+        self.pixel_count += 1;
     }
 
     pub fn push(&mut self, tile: u16, source: u32, priority: u16) {
@@ -70,9 +76,9 @@ impl PixelFifo {
                     let current_source = (self.pixel_sources >> t) & 0x3;
                     let current_color = (self.color_values >> t) & 0x3;
 
-                    // TODO: source < current_source is not correct should be source > current_source
-                    // Currently doesnt work properly.
-                    if i >= self.pixel_count || current_color == 0 || source < current_source {
+                    // Objects are pushed in priority order, so an opaque pixel that is already in the fifo stays on top.
+                    // Palettes don't affect the priority on DMG.
+                    if i >= self.pixel_count || current_color == 0 {
                         color_values |= (tile as u32) & (0x3 << t);
                         pixel_sources |= source << t;
                         background_priority |= priority << i;
@@ -0,0 +1,76 @@
+use super::{Ppu, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+// Formats the screen can be copied out in. Pixels are in the order of the screen buffer, multi-byte pixels are little endian.
+// Colors are derived from the RGBA values of color_shades, the raw shades only depend on the palette registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+    // Bytes of a pixel are R, G, B, A of its color. Same as the screen buffer.
+    Rgba8888,
+    // Bytes of a pixel are B, G, R, A of its color.
+    Bgra8888,
+    // 16 bits per pixel, R >> 3 in bits 11-15, G >> 2 in bits 5-10 and B >> 3 in bits 0-4. Alpha is dropped.
+    Rgb565,
+    // 15 bits per pixel in the CGB palette layout, B >> 3 in bits 10-14, G >> 3 in bits 5-9 and R >> 3 in bits 0-4.
+    // Bit 15 and alpha are dropped.
+    Bgr555,
+    // Shades 0 (lightest) to 3 (darkest) after the BGP, OBP0 and OBP1 palettes, 2 bits per pixel.
+    // Each byte holds 4 pixels, the first pixel is in the high bits.
+    Shade2bpp,
+}
+
+impl PixelFormat {
+    // Length of a screen in bytes in this format.
+    pub fn screen_size(&self) -> usize {
+        let pixels = SCREEN_WIDTH * SCREEN_HEIGHT;
+
+        match self {
+            Self::Rgba8888 | Self::Bgra8888 => pixels * 4,
+            Self::Rgb565 | Self::Bgr555 => pixels * 2,
+            Self::Shade2bpp => pixels / 4,
+        }
+    }
+}
+
+impl Ppu {
+    /// Copies the screen into the buffer in the given format, so a buffer can be reused for every frame.
+    /// # Panics
+    /// If the length of the buffer is not `format.screen_size()`.
+    pub fn copy_screen_pixels(&self, format: PixelFormat, pixels: &mut [u8]) {
+        assert_eq!(
+            pixels.len(),
+            format.screen_size(),
+            "buffer doesn't fit a screen in {format:?}"
+        );
+
+        match format {
+            PixelFormat::Shade2bpp => {
+                for (byte, shades) in pixels.iter_mut().zip(self.shade_buffer.chunks_exact(4)) {
+                    *byte = shades.iter().fold(0, |byte, shade| (byte << 2) | shade);
+                }
+            }
+            PixelFormat::Rgba8888 | PixelFormat::Bgra8888 => {
+                for (pixel, &color) in pixels.chunks_exact_mut(4).zip(self.screen_buffer.iter()) {
+                    let [r, g, b, a] = color.to_le_bytes();
+
+                    if format == PixelFormat::Rgba8888 {
+                        pixel.copy_from_slice(&[r, g, b, a]);
+                    } else {
+                        pixel.copy_from_slice(&[b, g, r, a]);
+                    }
+                }
+            }
+            PixelFormat::Rgb565 | PixelFormat::Bgr555 => {
+                for (pixel, &color) in pixels.chunks_exact_mut(2).zip(self.screen_buffer.iter()) {
+                    let [r, g, b, _] = color.to_le_bytes().map(|channel| channel as u16);
+
+                    let color = if format == PixelFormat::Rgb565 {
+                        ((r >> 3) << 11) | ((g >> 2) << 5) | (b >> 3)
+                    } else {
+                        ((b >> 3) << 10) | ((g >> 3) << 5) | (r >> 3)
+                    };
+                    pixel.copy_from_slice(&color.to_le_bytes());
+                }
+            }
+        }
+    }
+}
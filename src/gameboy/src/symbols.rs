@@ -0,0 +1,81 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io,
+    path::Path,
+};
+
+/*
+    Labels of a symbol file, a label in every line with its bank and address in hexadecimal:
+        00:0150 Start
+        01:4000 LoadLevel
+    This is the format of the .sym files written by rgblink and no$gmb, BGB reads them too.
+    Comments start with a semicolon, lines that are not labels are skipped.
+
+    Banks are numbered like rgblink does. ROM banks are numbered as in the cartridge, bank 0 is at 0x0000-0x3FFF.
+    External RAM is numbered by its bank and WRAM at 0xD000-0xDFFF is bank 1. Everything else is in bank 0.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct Symbols {
+    labels: BTreeMap<(u16, u16), String>,
+    addresses: HashMap<String, (u16, u16)>,
+}
+
+impl Symbols {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    pub fn parse(text: &str) -> Self {
+        let mut symbols = Self::default();
+
+        for line in text.lines() {
+            let line = line.split(';').next().unwrap().trim();
+
+            let Some((location, name)) = line.split_once(char::is_whitespace) else {
+                continue;
+            };
+            let Some((bank, address)) = location.split_once(':') else {
+                continue;
+            };
+            let (Ok(bank), Ok(address)) = (
+                u16::from_str_radix(bank, 16),
+                u16::from_str_radix(address, 16),
+            ) else {
+                continue;
+            };
+
+            // Labels are written to fixed width text buffers, so only ASCII names are kept.
+            let name = name.trim();
+            if !name.is_empty() && name.bytes().all(|byte| byte.is_ascii_graphic()) {
+                symbols.insert(bank, address, name);
+            }
+        }
+
+        symbols
+    }
+
+    /// Adds a label. When an address has many labels the first one is shown, the others are only found by name.
+    pub fn insert(&mut self, bank: u16, address: u16, name: &str) {
+        self.labels
+            .entry((bank, address))
+            .or_insert_with(|| name.to_owned());
+        self.addresses.insert(name.to_owned(), (bank, address));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn label(&self, bank: u16, address: u16) -> Option<&str> {
+        self.labels.get(&(bank, address)).map(String::as_str)
+    }
+
+    /// Bank and address of a label.
+    pub fn find(&self, name: &str) -> Option<(u16, u16)> {
+        self.addresses.get(name).copied()
+    }
+}
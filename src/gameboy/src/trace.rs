@@ -0,0 +1,74 @@
+use std::io::Write;
+
+use crate::{cpu::Cpu, memory_map::MemoryMap};
+
+/*
+    Cpu trace in the Gameboy Doctor format. A line is written before every instruction with the state it starts with:
+        A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0100 PCMEM:00,C3,13,02
+    Interrupt dispatches and the M-cycles of a halted cpu are not written, only the instructions.
+    Instructions of the boot rom are skipped, so booted and boot-skipped runs are compared with the same logs.
+    Known-good logs are recorded with LY fixed at 0x90, which is done with MemoryMap::ly_override.
+*/
+#[derive(Default)]
+pub struct CpuTrace {
+    writer: Option<Box<dyn Write>>,
+}
+
+impl CpuTrace {
+    pub fn new(writer: impl Write + 'static) -> Self {
+        Self {
+            writer: Some(Box::new(writer)),
+        }
+    }
+
+    pub fn trace(&mut self, cpu: &Cpu, memory_map: &MemoryMap) {
+        let Some(writer) = &mut self.writer else {
+            return;
+        };
+
+        if !memory_map.boot_rom.is_empty() {
+            return;
+        }
+
+        let registers = &cpu.registers;
+        let pc = cpu.pc;
+        let pc_memory = [0, 1, 2, 3].map(|offset| memory_map.get(pc.wrapping_add(offset)));
+
+        let result = writeln!(
+            writer,
+            "A:{:02X} F:{:02X} B:{:02X} C:{:02X} D:{:02X} E:{:02X} H:{:02X} L:{:02X} SP:{:04X} PC:{:04X} PCMEM:{:02X},{:02X},{:02X},{:02X}",
+            registers.a,
+            registers.af() & 0xFF,
+            registers.b,
+            registers.c,
+            registers.d,
+            registers.e,
+            registers.h,
+            registers.l,
+            cpu.sp,
+            pc,
+            pc_memory[0],
+            pc_memory[1],
+            pc_memory[2],
+            pc_memory[3],
+        );
+
+        // Tracing stops when the log can't be written, the emulation goes on without it.
+        if result.is_err() {
+            self.writer = None;
+        }
+    }
+
+    pub fn flush(&mut self) {
+        if let Some(writer) = &mut self.writer {
+            let _ = writer.flush();
+        }
+    }
+}
+
+// Writers can't be cloned, a cloned emulator starts without a trace.
+impl Clone for CpuTrace {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
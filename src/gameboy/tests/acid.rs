@@ -1,4 +1,9 @@
 #[macro_use]
 mod common;
 
+/*
+    dmg-acid2 draws a face that breaks when a ppu feature is missing.
+    Among others it covers the object priority by X and OAM order, the 10 objects per line limit,
+    bit 0 of the tile index being ignored for 8x16 objects and vertical flip of 8x16 objects over both tiles.
+*/
 create_tests!(acid, dmg_acid2);
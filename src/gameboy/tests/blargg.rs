@@ -7,8 +7,26 @@ create_tests!(
     instr_timing,
     mem_timing,
     mem_timing_2,
-    oam_bug,
     halt_bug,
     interrupt_time,
     dmg_sound
 );
+
+/*
+    Blargg's test roms also write the result to the cartridge RAM, a status byte at 0xA000 followed by the DE B0 61 signature.
+    Status is 0 when the test passes. OAM bug roms are checked this way, the text they print doesn't fit on the screen.
+*/
+create_memory_tests!(
+    oam_bug in "blargg/oam_bug", 0xA000 => [0x00, 0xDE, 0xB0, 0x61],
+    lcd_sync in "1-lcd_sync",
+    causes in "2-causes",
+    non_causes in "3-non_causes",
+    scanline_timing in "4-scanline_timing",
+    timing_bug in "5-timing_bug",
+    timing_no_bug in "6-timing_no_bug",
+    // Rom prints the OAM after the corruption at every timing, the text is written to the cartridge RAM from 0xA004.
+    // It runs past the 8KB of cartridge RAM into the rom code that is copied to 0xC000, so the rom crashes.
+    #[ignore = "rom overwrites its code with the printed text and crashes before writing the result"]
+    timing_effect in "7-timing_effect",
+    instr_effect in "8-instr_effect"
+);
@@ -0,0 +1,71 @@
+/*
+    Tests of loading cartridges, invalid or unsupported cartridges are reported as errors.
+*/
+
+use gameboy::Gameboy;
+use std::path::{Path, PathBuf};
+
+// Writes a 32KB rom with the given cartridge type and ram size in its header.
+fn write_rom(name: &str, cartridge_type: u8, ram_size: u8) -> PathBuf {
+    let mut rom = vec![0u8; 0x8000];
+    rom[0x147] = cartridge_type;
+    rom[0x149] = ram_size;
+
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    std::fs::write(&path, rom).unwrap();
+    path
+}
+
+fn load_error(path: &Path) -> String {
+    let mut emulator = Gameboy::after_boot();
+
+    match emulator.load_cartidge(path) {
+        Ok(()) => panic!("{} is loaded.", path.display()),
+        Err(error) => error.to_string(),
+    }
+}
+
+#[test]
+fn loads_supported_cartridges() {
+    for (name, cartridge_type, ram_size) in [("no_mbc.gb", 0x00, 0x00), ("mbc1.gb", 0x03, 0x03)] {
+        let path = write_rom(name, cartridge_type, ram_size);
+
+        Gameboy::after_boot().load_cartidge(&path).unwrap();
+    }
+}
+
+#[test]
+fn missing_file_is_an_error() {
+    load_error(&Path::new(env!("CARGO_TARGET_TMPDIR")).join("missing.gb"));
+}
+
+#[test]
+fn unsupported_mbc_is_an_error() {
+    // MBC2, MBC3 and MBC5.
+    for cartridge_type in [0x05, 0x13, 0x1B] {
+        let path = write_rom("unsupported_mbc.gb", cartridge_type, 0x00);
+
+        assert_eq!(
+            load_error(&path),
+            format!("cartridge type {cartridge_type:#04X} is not supported")
+        );
+    }
+}
+
+#[test]
+fn invalid_ram_size_is_an_error() {
+    let path = write_rom("invalid_ram_size.gb", 0x03, 0x01);
+
+    assert_eq!(load_error(&path), "ram size 0x01 is not valid");
+}
+
+#[test]
+fn rom_without_header_is_an_error() {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_header.gb");
+    std::fs::write(&path, [0u8; 0x100]).unwrap();
+
+    assert_eq!(
+        load_error(&path),
+        "rom is 256 bytes, too small to have a header"
+    );
+}
@@ -1,53 +1,223 @@
 use gameboy::Gameboy;
-use image::EncodableLayout;
-use std::{path::PathBuf, time::Duration};
+use image::{DynamicImage, Rgba, RgbaImage};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
-fn run_test_rom(mut path: PathBuf) {
-    let mut emulator = Gameboy::after_boot();
+/// How a test rom signals that it has finished running.
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+pub enum Completion {
+    /// Rom jumps to itself in an infinite loop.
+    InfiniteLoop,
+    /// Rom executes the `LD B,B` debug breakpoint. Used by the mealybug tearoom test suite
+    /// and the manual mooneye tests.
+    LdBB,
+    /// Rom executes the `LD B,B` debug breakpoint and passes with the fibonacci numbers in the registers.
+    /// Used by the mooneye and SameSuite test suites. Screen is not compared.
+    Fibonacci,
+    /// Rom executes the `LD B,B` debug breakpoint or jumps to itself in an infinite loop,
+    /// and passes with the bytes in memory starting at the address. Screen is not compared.
+    Memory(u16, &'static [u8]),
+}
+
+// Registers B, C, D, E, H and L of a passing mooneye test.
+const FIBONACCI_REGISTERS: [u8; 6] = [3, 5, 8, 13, 21, 34];
+
+// Grayscale colors of the shades in the reference images.
+const REFERENCE_COLORS: [[u8; 4]; 4] = [
+    [0xFF, 0xFF, 0xFF, 0xFF],
+    [0xAA, 0xAA, 0xAA, 0xFF],
+    [0x55, 0x55, 0x55, 0xFF],
+    [0x00, 0x00, 0x00, 0xFF],
+];
 
-    emulator.load_cartidge(&path);
+/// Runs a test rom and checks its result.
+/// Returns the T-cycles it took to finish, or a message describing the failure.
+/// When the screen doesn't match, the expected, actual and diff images are written to the target directory.
+pub fn check_test_rom(path: &Path, completion: Completion) -> Result<u64, String> {
+    let mut emulator = Gameboy::after_boot();
 
-    emulator.ppu.color_shades = [0xFFFFFFFF, 0xFFAAAAAA, 0xFF555555, 0xFF000000];
+    emulator
+        .load_cartidge(path)
+        .map_err(|error| format!("Cannot load {}: {error}", path.display()))?;
 
     let mut finished = false;
     let mut old_pc = 0;
 
     emulator.debug_cycle(Duration::from_secs(30), |emulator| {
-        if old_pc == emulator.cpu.pc {
-            finished = true;
-        } else {
-            old_pc = emulator.cpu.pc;
+        match completion {
+            Completion::InfiniteLoop | Completion::Memory(..) => {
+                // Program counter doesn't change while the cpu is halted.
+                // A halt with IME disabled is only waiting for an event in the middle of the test.
+                let waiting = emulator.cpu.is_halted() && !emulator.cpu.ime;
+
+                if old_pc == emulator.cpu.pc && !waiting {
+                    finished = true;
+                } else {
+                    old_pc = emulator.cpu.pc;
+                }
+            }
+            Completion::LdBB | Completion::Fibonacci => {}
+        }
+
+        if !matches!(completion, Completion::InfiniteLoop) {
+            finished |= emulator.decode_instr(emulator.cpu.pc).name == "LD B,B";
         }
 
         finished
     });
 
-    emulator.cycle(Duration::from_secs(1));
+    let cycles = emulator.total_cycles();
 
-    assert!(finished, "Could not finished the rom in 30 seconds.");
+    // Finish the current frame and draw a complete one after it.
+    emulator.run_frame();
+    emulator.run_frame();
 
-    // Compare results.
-    path.set_extension("png"); // Turn it to image path.
-    let success_image = image::open(&path).unwrap().into_rgba8();
+    let stem = path.file_stem().unwrap().to_str().unwrap().to_owned();
+
+    if !finished {
+        return Err(format!(
+            "Test case {stem} could not finish in 30 seconds ({cycles} cycles)."
+        ));
+    }
+
+    match completion {
+        Completion::Fibonacci => {
+            let registers = &emulator.cpu.registers;
+            let values = [
+                registers.b,
+                registers.c,
+                registers.d,
+                registers.e,
+                registers.h,
+                registers.l,
+            ];
 
-    let screen_buffer = unsafe {
-        let length = emulator.ppu.screen_buffer.len() * std::mem::size_of::<u32>();
+            return if values == FIBONACCI_REGISTERS {
+                Ok(cycles)
+            } else {
+                Err(format!(
+                    "Test case {stem} failed after {cycles} cycles with registers {values:?}."
+                ))
+            };
+        }
+        Completion::Memory(address, expected) => {
+            let values: Vec<u8> = (address..)
+                .take(expected.len())
+                .map(|address| emulator.memory_map.get(address))
+                .collect();
+
+            return if values == expected {
+                Ok(cycles)
+            } else {
+                Err(format!(
+                    "Test case {stem} failed after {cycles} cycles with {values:02X?} at {address:#06X}."
+                ))
+            };
+        }
+        Completion::InfiniteLoop | Completion::LdBB => {}
+    }
 
-        std::slice::from_raw_parts(
-            Box::into_raw(emulator.ppu.screen_buffer) as *const u8,
-            length,
-        )
+    // Compare results.
+    // Prefer the DMG specific image when a test has results for multiple models.
+    // Tests with results that vary between DMG units have numbered images, any of them is accepted.
+    let reference_paths: Vec<PathBuf> = match [
+        format!("{stem}.dmg.png"),
+        format!("{stem}_dmg_blob.png"), // Mealybug tearoom tests.
+        format!("{stem}.png"),
+    ]
+    .into_iter()
+    .map(|file_name| path.with_file_name(file_name))
+    .find(|path| path.exists())
+    {
+        Some(path) => vec![path],
+        None => (0..)
+            .map(|index| path.with_file_name(format!("{stem}_{index}.dmg.png")))
+            .take_while(|path| path.exists())
+            .collect(),
     };
 
-    assert!(
-        success_image.as_bytes() == screen_buffer,
-        "Test case {} failed.",
-        &path.file_stem().unwrap().to_str().unwrap()
-    );
+    if reference_paths.is_empty() {
+        return Err(format!("Test case {stem} has no reference image."));
+    }
+
+    // Reference images are grayscale, shades are compared so the results don't depend on the screen colors.
+    // The closest reference image is reported when none of them match.
+    let (mismatches, success_image) = reference_paths
+        .iter()
+        .map(|path| {
+            let success_image = image::open(path).unwrap().into_rgba8();
+            let mismatches = success_image
+                .pixels()
+                .zip(emulator.ppu.shade_buffer.iter())
+                .filter(|(pixel, &shade)| REFERENCE_COLORS.get(shade as usize) != Some(&pixel.0))
+                .count();
+
+            (mismatches, success_image)
+        })
+        .min_by_key(|(mismatches, _)| *mismatches)
+        .unwrap();
+
+    if mismatches == 0 {
+        return Ok(cycles);
+    }
+
+    let actual_image = RgbaImage::from_fn(success_image.width(), success_image.height(), |x, y| {
+        let shade = emulator.ppu.shade_buffer[(y * success_image.width() + x) as usize];
+        Rgba(REFERENCE_COLORS[shade as usize])
+    });
+
+    // Matching pixels are faded so the mismatched ones stand out in red.
+    let diff_image = RgbaImage::from_fn(success_image.width(), success_image.height(), |x, y| {
+        let expected = success_image.get_pixel(x, y);
+
+        if expected == actual_image.get_pixel(x, y) {
+            Rgba(expected.0.map(|channel| 0xC0 + channel / 4))
+        } else {
+            Rgba([0xFF, 0x00, 0x00, 0xFF])
+        }
+    });
+
+    let similarity = image_compare::gray_similarity_structure(
+        &image_compare::Algorithm::MSSIMSimple,
+        &DynamicImage::ImageRgba8(success_image.clone()).into_luma8(),
+        &DynamicImage::ImageRgba8(actual_image.clone()).into_luma8(),
+    )
+    .map(|similarity| similarity.score)
+    .unwrap_or(0.0);
+
+    let output_dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .join("test_roms")
+        .join(path.parent().unwrap().file_name().unwrap());
+    std::fs::create_dir_all(&output_dir).unwrap();
+
+    for (suffix, image) in [
+        ("expected", &success_image),
+        ("actual", &actual_image),
+        ("diff", &diff_image),
+    ] {
+        image
+            .save(output_dir.join(format!("{stem}.{suffix}.png")))
+            .unwrap();
+    }
+
+    Err(format!(
+        "Test case {stem} failed after {cycles} cycles, {mismatches} pixels don't match \
+        (similarity {similarity:.3}). Images are written to {}.",
+        output_dir.display()
+    ))
 }
 
-pub fn execute_tests(test_dir_path: &'static str) {
-    let mut path = std::path::PathBuf::from(test_dir_path);
+fn run_test_rom(path: PathBuf, completion: Completion) {
+    if let Err(message) = check_test_rom(&path, completion) {
+        panic!("{message}");
+    }
+}
+
+pub fn execute_tests(test_dir_path: impl Into<PathBuf>, completion: Completion) {
+    let mut path = test_dir_path.into();
 
     if path.exists() {
         // Folder
@@ -58,28 +228,77 @@ pub fn execute_tests(test_dir_path: &'static str) {
 
             if let Some(extension) = path.extension() {
                 if extension.to_str() == Some("gb") {
-                    run_test_rom(path);
+                    run_test_rom(path, completion);
                 }
             }
         }
     } else {
         // File
         path.set_extension("gb");
-        run_test_rom(path);
+        run_test_rom(path, completion);
     }
 }
 
 #[macro_export]
 macro_rules! create_tests {
-    ($parent: ident, $($name: ident),+) => {
+    ($parent: ident in $dir: literal, $($(#[$meta: meta])* $name: ident $(in $file: literal)?),+) => {
+        create_tests!(@impl [InfiniteLoop], $parent [$dir], $($(#[$meta])* $name $(in $file)?),+);
+    };
+    ($parent: ident, $($(#[$meta: meta])* $name: ident $(in $file: literal)?),+) => {
+        create_tests!(@impl [InfiniteLoop], $parent [stringify!($parent)], $($(#[$meta])* $name $(in $file)?),+);
+    };
+    (@impl [$($completion: tt)+], $parent: ident [$($dir: tt)+], $($(#[$meta: meta])* $name: ident $(in $file: literal)?),+) => {
         #[cfg(test)]
         mod $parent {
+            const TEST_DIR_PATH: &str = concat!("../../roms/test/", $($dir)+);
+            const COMPLETION: crate::common::Completion = crate::common::Completion::$($completion)+;
+
             $(
                 #[test]
+                $(#[$meta])*
                 fn $name() {
-                    crate::common::execute_tests(concat!("../../roms/test/", stringify!($parent), "/", stringify!($name)));
+                    crate::common::execute_tests(
+                        std::path::Path::new(TEST_DIR_PATH).join(test_rom_name!($name $($file)?)),
+                        COMPLETION,
+                    );
                 }
             )+
         }
     };
 }
+
+// Mooneye test roms finish with the LD B,B debug breakpoint and report the result in the registers.
+#[macro_export]
+macro_rules! create_mooneye_tests {
+    ($parent: ident in $dir: literal, $($(#[$meta: meta])* $name: ident $(in $file: literal)?),+) => {
+        create_tests!(@impl [Fibonacci], $parent [$dir], $($(#[$meta])* $name $(in $file)?),+);
+    };
+}
+
+// Mealybug tearoom test roms also finish with the LD B,B debug breakpoint.
+#[macro_export]
+macro_rules! create_mealybug_tests {
+    ($parent: ident in $dir: literal, $($(#[$meta: meta])* $name: ident $(in $file: literal)?),+) => {
+        create_tests!(@impl [LdBB], $parent [$dir], $($(#[$meta])* $name $(in $file)?),+);
+    };
+}
+
+// Test roms that report the result in memory, they finish with the LD B,B debug breakpoint or an infinite loop.
+// Expected bytes are given with the address they start at: `parent in "dir", 0xA000 => [0x00, 0x01], name, ...`.
+#[macro_export]
+macro_rules! create_memory_tests {
+    ($parent: ident in $dir: literal, $address: literal => $expected: expr, $($(#[$meta: meta])* $name: ident $(in $file: literal)?),+) => {
+        create_tests!(@impl [Memory($address, &$expected)], $parent [$dir], $($(#[$meta])* $name $(in $file)?),+);
+    };
+}
+
+// Test roms with names that are not valid identifiers can be given explicitly.
+#[macro_export]
+macro_rules! test_rom_name {
+    ($name: ident) => {
+        stringify!($name)
+    };
+    ($name: ident $file: literal) => {
+        $file
+    };
+}
@@ -0,0 +1,124 @@
+/*
+    Tests of the cpu on its own, on a flat 64KB bus without any hardware behind it.
+*/
+
+use gameboy::{
+    bus::FlatBus,
+    cpu::{Cpu, CpuState},
+    instructions::{FlagEffect, INSTRUCTIONS, PREFIX_CB_INSTRUCTIONS},
+    memory_map::Io,
+};
+
+const ILLEGAL_OPCODES: [u8; 11] = [
+    0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD,
+];
+
+// Illegal opcodes lock the cpu at their address, a pending interrupt doesn't wake it up even with IME set.
+#[test]
+fn illegal_opcodes_lock_the_cpu() {
+    for opcode in ILLEGAL_OPCODES {
+        let mut bus = FlatBus::new();
+        bus.memory[0x100] = opcode;
+
+        let mut cpu = Cpu::after_boot();
+        cpu.ime = true;
+
+        assert!(cpu.cycle(&mut bus), "{opcode:#04X} is not executed.");
+        assert_eq!(cpu.state(), CpuState::Locked(0x100), "{opcode:#04X}");
+
+        bus.memory[Io::IE as usize] = 0x01;
+        bus.memory[Io::IF as usize] = 0x01;
+        bus.cycles.clear();
+
+        for _ in 0..10 {
+            assert!(!cpu.cycle(&mut bus));
+        }
+
+        assert_eq!(cpu.state(), CpuState::Locked(0x100), "{opcode:#04X}");
+        assert_eq!(cpu.pc, 0x101, "{opcode:#04X} dispatched an interrupt.");
+        assert_eq!(cpu.sp, 0xFFFE, "{opcode:#04X} dispatched an interrupt.");
+        assert!(cpu.ime, "{opcode:#04X} dispatched an interrupt.");
+        assert_eq!(bus.memory[Io::IF as usize], 0x01);
+        assert!(bus.cycles.is_empty(), "{opcode:#04X} accessed the bus.");
+    }
+}
+
+// Condition of a conditional jump, call or return is in bits 3-4 of the opcode: NZ, Z, NC or C.
+fn is_condition_true(opcode: u8, flags: u8) -> bool {
+    let zero = flags & 0x80 != 0;
+    let carry = flags & 0x10 != 0;
+
+    match (opcode >> 3) & 0x3 {
+        0 => !zero,
+        1 => zero,
+        2 => !carry,
+        _ => carry,
+    }
+}
+
+// Every opcode takes the cycles of the opcode table and changes the flags the way the table says.
+// Opcodes run once with all flags reset and once with all flags set, so conditions are both false and true.
+#[test]
+fn opcodes_match_the_opcode_table() {
+    for prefixed in [false, true] {
+        for opcode in 0..=0xFF {
+            if !prefixed && (opcode == 0xCB || ILLEGAL_OPCODES.contains(&opcode)) {
+                continue;
+            }
+
+            let (instruction, code) = if prefixed {
+                (
+                    PREFIX_CB_INSTRUCTIONS[opcode as usize],
+                    [0xCB, opcode, 0x10, 0x20],
+                )
+            } else {
+                (INSTRUCTIONS[opcode as usize], [opcode, 0x10, 0x20, 0x00])
+            };
+            let name = instruction.name;
+
+            for flags in [0x00, 0xF0] {
+                let mut bus = FlatBus::new();
+                bus.memory[0x100..0x104].copy_from_slice(&code);
+
+                let mut cpu = Cpu::after_boot();
+                cpu.registers
+                    .set_af(((cpu.registers.a as u16) << 8) | flags as u16);
+
+                assert!(cpu.cycle(&mut bus), "{name} is not executed.");
+
+                let cycles = if instruction.is_conditional() && is_condition_true(opcode, flags) {
+                    instruction.branch_cycles
+                } else {
+                    instruction.cycles
+                };
+                assert_eq!(
+                    cpu.clock_cycles, cycles as u32,
+                    "{name} with flags {flags:#04X}"
+                );
+
+                let effects = [
+                    (instruction.flags.zero, 0x80),
+                    (instruction.flags.subtract, 0x40),
+                    (instruction.flags.half_carry, 0x20),
+                    (instruction.flags.carry, 0x10),
+                ];
+                let new_flags = cpu.registers.af() as u8;
+
+                for (effect, mask) in effects {
+                    let expected = match effect {
+                        FlagEffect::Unaffected => flags & mask != 0,
+                        FlagEffect::Reset => false,
+                        FlagEffect::Set => true,
+                        FlagEffect::Affected => continue,
+                    };
+
+                    assert_eq!(
+                        new_flags & mask != 0,
+                        expected,
+                        "{name} with flags {flags:#04X}, flag {mask:#04X} is {effect:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+#[macro_use]
+mod common;
+
+// Speed switch tests are omitted, they are CGB only roms.
+create_tests!(
+    daid,
+    ppu_scanline_bgp,
+    #[ignore = "prints the SRAM behaviour of the cartridge, there is no pass result to check"]
+    rom_and_ram,
+    stop_instr,
+    #[ignore = "result image is only available for CGB"]
+    stop_instr_gbc_mode3
+);
@@ -0,0 +1,107 @@
+/*
+    Tests of the rom disassembler. The sources of the banks are read back line by line,
+    every byte of the rom must be written exactly once, by an instruction or by data.
+*/
+
+use gameboy::disassembler::{disassemble, disassemble_rom};
+
+const BANK_SIZE: usize = 0x4000;
+
+// Parses a number of the source, written in decimal or in hexadecimal with a $ prefix.
+fn parse_number(text: &str) -> usize {
+    match text.trim().strip_prefix('$') {
+        Some(hex) => usize::from_str_radix(hex, 16).unwrap(),
+        None => text.trim().parse().unwrap(),
+    }
+}
+
+// Rom offsets that every line of the source of a bank writes, checked against the bytes of the rom.
+fn written_offsets(rom: &[u8], bank: usize, source: &str) -> Vec<usize> {
+    let start = bank * BANK_SIZE;
+    let base_address = if bank == 0 { 0 } else { BANK_SIZE };
+
+    let mut offsets = Vec::new();
+    let mut offset = start;
+
+    for line in source.lines() {
+        // Instructions written as data have the instruction in a comment.
+        let line = line.split(';').next().unwrap().trim();
+        if line.is_empty() || line.starts_with("SECTION") || line.ends_with(':') {
+            continue;
+        }
+
+        let (keyword, arguments) = line.split_once(' ').unwrap_or((line, ""));
+        let length = match keyword {
+            "db" => {
+                for (index, byte) in arguments.split(',').enumerate() {
+                    assert_eq!(parse_number(byte), rom[offset + index] as usize, "{line}");
+                }
+                arguments.split(',').count()
+            }
+            "dw" => {
+                if arguments.starts_with('$') {
+                    let pointer = u16::from_le_bytes([rom[offset], rom[offset + 1]]);
+                    assert_eq!(parse_number(arguments), pointer as usize, "{line}");
+                }
+                2
+            }
+            "ds" => {
+                let (count, value) = arguments.split_once(',').unwrap();
+                let count = parse_number(count);
+                assert!(rom[offset..offset + count]
+                    .iter()
+                    .all(|&byte| byte as usize == parse_number(value)));
+                count
+            }
+            _ => {
+                let address = (offset - start + base_address) as u16;
+                let instruction = disassemble(address, |address| {
+                    rom.get(start + address as usize - base_address)
+                        .copied()
+                        .unwrap_or(0xFF)
+                });
+
+                let expected = instruction.to_string();
+                assert_eq!(
+                    keyword,
+                    expected.split(' ').next().unwrap(),
+                    "{line} at {offset:x}"
+                );
+                instruction.length as usize
+            }
+        };
+
+        offsets.extend(offset..offset + length);
+        offset += length;
+    }
+
+    offsets
+}
+
+fn assert_covers_rom(path: &str) {
+    let rom = std::fs::read(path).unwrap();
+    let disassembly = disassemble_rom(&rom);
+
+    let mut writes = vec![0; rom.len()];
+    for bank in 0..disassembly.bank_count() {
+        for offset in written_offsets(&rom, bank, &disassembly.bank_source(bank)) {
+            writes[offset] += 1;
+        }
+    }
+
+    assert!(disassembly.code_size() > 0);
+    if let Some(offset) = writes.iter().position(|&count| count != 1) {
+        panic!("Byte at {offset:x} is written {} times", writes[offset]);
+    }
+}
+
+#[test]
+fn bank_source_covers_every_byte_once() {
+    assert_covers_rom("../../roms/test/acid/dmg_acid2.gb");
+}
+
+// Banked rom, its code jumps between the banks.
+#[test]
+fn bank_source_covers_every_byte_once_with_banks() {
+    assert_covers_rom("../../roms/mario.gb");
+}
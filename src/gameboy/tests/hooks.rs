@@ -0,0 +1,55 @@
+/*
+    Tests of the ppu hooks, they are called while the emulator runs and are shared with the clones of the ppu.
+*/
+
+use gameboy::Gameboy;
+use std::{cell::Cell, rc::Rc};
+
+const ROM_PATH: &str = "../../roms/test/acid/dmg_acid2.gb";
+
+fn emulator_counting_scanlines() -> (Gameboy, Rc<Cell<u32>>) {
+    let mut emulator = Gameboy::after_boot();
+    emulator.load_cartidge(ROM_PATH).unwrap();
+
+    // Skip the frames until the rom turns the LCD on and draws.
+    for _ in 0..10 {
+        emulator.run_frame();
+    }
+
+    let scanlines = Rc::new(Cell::new(0));
+    let counter = scanlines.clone();
+    emulator
+        .ppu
+        .set_scanline_hook(move |_, _| counter.set(counter.get() + 1));
+
+    (emulator, scanlines)
+}
+
+#[test]
+fn scanline_hook_is_called_for_every_visible_line() {
+    let (mut emulator, scanlines) = emulator_counting_scanlines();
+
+    emulator.run_frame();
+
+    assert_eq!(scanlines.get(), 144);
+}
+
+#[test]
+fn clones_share_the_hooks() {
+    let (mut emulator, scanlines) = emulator_counting_scanlines();
+
+    let mut clone = emulator.clone();
+    clone.run_frame();
+
+    assert_eq!(scanlines.get(), 144);
+
+    // Clearing the hooks of the clone doesn't clear the hooks of the original.
+    clone.ppu.clear_hooks();
+    clone.run_frame();
+
+    assert_eq!(scanlines.get(), 144);
+
+    emulator.run_frame();
+
+    assert_eq!(scanlines.get(), 288);
+}
@@ -0,0 +1,194 @@
+#[macro_use]
+mod common;
+
+/*
+    Tests for the other models are omitted, only DMG ABC is emulated:
+        boot_div-S, boot_div-dmg0, boot_div2-S, boot_hwio-S, boot_hwio-dmg0,
+        boot_regs-dmg0, boot_regs-mgb, boot_regs-sgb, boot_regs-sgb2.
+*/
+create_mooneye_tests!(
+    acceptance in "mooneye/acceptance",
+    add_sp_e_timing,
+    #[ignore = "DIV doesn't have the value it has after the boot rom"]
+    boot_div_dmg_abc_mgb in "boot_div-dmgABCmgb",
+    #[ignore = "unused I/O registers don't have the values they have after the boot rom"]
+    boot_hwio_dmg_abc_mgb in "boot_hwio-dmgABCmgb",
+    boot_regs_dmg_abc in "boot_regs-dmgABC",
+    call_cc_timing,
+    call_cc_timing2,
+    call_timing,
+    call_timing2,
+    di_timing_gs in "di_timing-GS",
+    div_timing,
+    ei_sequence,
+    ei_timing,
+    halt_ime0_ei,
+    halt_ime0_nointr_timing,
+    halt_ime1_timing,
+    halt_ime1_timing2_gs in "halt_ime1_timing2-GS",
+    if_ie_registers,
+    intr_timing,
+    jp_cc_timing,
+    jp_timing,
+    ld_hl_sp_e_timing,
+    oam_dma_restart,
+    oam_dma_start,
+    oam_dma_timing,
+    pop_timing,
+    push_timing,
+    rapid_di_ei,
+    ret_cc_timing,
+    ret_timing,
+    reti_intr_timing,
+    reti_timing,
+    rst_timing
+);
+
+create_mooneye_tests!(
+    bits in "mooneye/acceptance/bits",
+    mem_oam,
+    reg_f,
+    #[ignore = "unused bits of the I/O registers don't read as 1"]
+    unused_hwio_gs in "unused_hwio-GS"
+);
+
+create_mooneye_tests!(instr in "mooneye/acceptance/instr", daa);
+
+create_mooneye_tests!(interrupts in "mooneye/acceptance/interrupts", ie_push);
+
+create_mooneye_tests!(
+    oam_dma in "mooneye/acceptance/oam_dma",
+    basic,
+    reg_read,
+    #[ignore = "requires MBC5"]
+    sources_gs in "sources-GS"
+);
+
+create_mooneye_tests!(
+    ppu in "mooneye/acceptance/ppu",
+    hblank_ly_scx_timing_gs in "hblank_ly_scx_timing-GS",
+    intr_1_2_timing_gs in "intr_1_2_timing-GS",
+    intr_2_0_timing,
+    intr_2_mode0_timing,
+    intr_2_mode0_timing_sprites,
+    intr_2_mode3_timing,
+    intr_2_oam_ok_timing,
+    lcdon_timing_gs in "lcdon_timing-GS",
+    lcdon_write_timing_gs in "lcdon_write_timing-GS",
+    stat_irq_blocking,
+    stat_lyc_onoff,
+    vblank_stat_intr_gs in "vblank_stat_intr-GS"
+);
+
+create_mooneye_tests!(
+    serial in "mooneye/acceptance/serial",
+    #[ignore = "serial clock is taken from the system counter, which doesn't have the value it has after the boot rom"]
+    boot_sclk_align_dmg_abc_mgb in "boot_sclk_align-dmgABCmgb"
+);
+
+create_mooneye_tests!(
+    timer in "mooneye/acceptance/timer",
+    div_write,
+    #[ignore = "TIMA doesn't increment when TAC is disabled on a falling edge"]
+    rapid_toggle,
+    tim00,
+    tim00_div_trigger,
+    tim01,
+    tim01_div_trigger,
+    tim10,
+    tim10_div_trigger,
+    tim11,
+    tim11_div_trigger,
+    tima_reload,
+    #[ignore = "requires exact TIMA reload timing"]
+    tima_write_reloading,
+    #[ignore = "requires exact TIMA reload timing"]
+    tma_write_reloading
+);
+
+create_mooneye_tests!(
+    mbc1 in "mooneye/emulator-only/mbc1",
+    bits_bank1,
+    bits_bank2,
+    bits_mode,
+    bits_ramg,
+    #[ignore = "MBC1 multicart wiring is not emulated"]
+    multicart_rom_8mb in "multicart_rom_8Mb",
+    ram_256kb,
+    #[ignore = "MBC1 RAM banking is incomplete"]
+    ram_64kb,
+    #[ignore = "MBC1 secondary ROM bank mapping is incomplete"]
+    rom_16mb in "rom_16Mb",
+    rom_1mb in "rom_1Mb",
+    rom_2mb in "rom_2Mb",
+    rom_4mb in "rom_4Mb",
+    rom_512kb,
+    #[ignore = "MBC1 secondary ROM bank mapping is incomplete"]
+    rom_8mb in "rom_8Mb"
+);
+
+create_mooneye_tests!(
+    mbc2 in "mooneye/emulator-only/mbc2",
+    #[ignore = "requires MBC2"]
+    bits_ramg,
+    #[ignore = "requires MBC2"]
+    bits_romb,
+    #[ignore = "requires MBC2"]
+    bits_unused,
+    #[ignore = "requires MBC2"]
+    ram,
+    #[ignore = "requires MBC2"]
+    rom_1mb in "rom_1Mb",
+    #[ignore = "requires MBC2"]
+    rom_2mb in "rom_2Mb",
+    #[ignore = "requires MBC2"]
+    rom_512kb
+);
+
+create_mooneye_tests!(
+    mbc5 in "mooneye/emulator-only/mbc5",
+    #[ignore = "requires MBC5"]
+    rom_16mb in "rom_16Mb",
+    #[ignore = "requires MBC5"]
+    rom_1mb in "rom_1Mb",
+    #[ignore = "requires MBC5"]
+    rom_2mb in "rom_2Mb",
+    #[ignore = "requires MBC5"]
+    rom_4mb in "rom_4Mb",
+    #[ignore = "requires MBC5"]
+    rom_512kb,
+    #[ignore = "requires MBC5"]
+    rom_8mb in "rom_8Mb"
+);
+
+// Misc tests are for CGB and AGB, they are expected to fail on DMG.
+create_mooneye_tests!(
+    misc in "mooneye/misc",
+    #[ignore = "requires AGB"]
+    boot_div_a in "boot_div-A",
+    #[ignore = "requires CGB"]
+    boot_div_cgb0 in "boot_div-cgb0",
+    #[ignore = "requires CGB"]
+    boot_div_cgb_abcde in "boot_div-cgbABCDE",
+    #[ignore = "requires CGB"]
+    boot_hwio_c in "boot_hwio-C",
+    #[ignore = "requires AGB"]
+    boot_regs_a in "boot_regs-A",
+    #[ignore = "requires CGB"]
+    boot_regs_cgb in "boot_regs-cgb"
+);
+
+create_mooneye_tests!(
+    misc_bits in "mooneye/misc/bits",
+    #[ignore = "requires CGB"]
+    unused_hwio_c in "unused_hwio-C"
+);
+
+create_mooneye_tests!(
+    misc_ppu in "mooneye/misc/ppu",
+    #[ignore = "requires CGB"]
+    vblank_stat_intr_c in "vblank_stat_intr-C"
+);
+
+// Manual tests don't report a result in the registers, the screen is compared instead.
+create_mealybug_tests!(manual_only in "mooneye/manual-only", sprite_priority);
@@ -0,0 +1,71 @@
+/*
+    Tests of copying the screen out in the pixel formats, byte by byte.
+*/
+
+use gameboy::ppu::{PixelFormat, Ppu};
+
+// R 0xF8, G 0x84, B 0x0C and A 0x80, every channel loses different bits in the 16 bit formats.
+const COLOR: u32 = 0x800C84F8;
+
+fn ppu_with_first_pixels() -> Ppu {
+    let mut ppu = Ppu::new();
+    ppu.screen_buffer[0] = COLOR;
+    ppu.screen_buffer[1] = 0xFF000000;
+    ppu.shade_buffer[..8].copy_from_slice(&[0, 1, 2, 3, 3, 3, 0, 0]);
+    ppu
+}
+
+fn first_bytes(format: PixelFormat, count: usize) -> Vec<u8> {
+    let mut pixels = vec![0xAA; format.screen_size()];
+    ppu_with_first_pixels().copy_screen_pixels(format, &mut pixels);
+    pixels[..count].to_vec()
+}
+
+#[test]
+fn rgba8888() {
+    assert_eq!(
+        first_bytes(PixelFormat::Rgba8888, 8),
+        [0xF8, 0x84, 0x0C, 0x80, 0x00, 0x00, 0x00, 0xFF]
+    );
+}
+
+#[test]
+fn bgra8888() {
+    assert_eq!(
+        first_bytes(PixelFormat::Bgra8888, 8),
+        [0x0C, 0x84, 0xF8, 0x80, 0x00, 0x00, 0x00, 0xFF]
+    );
+}
+
+#[test]
+fn rgb565() {
+    // 0b11111_100001_00001
+    assert_eq!(
+        first_bytes(PixelFormat::Rgb565, 4),
+        [0x21, 0xFC, 0x00, 0x00]
+    );
+}
+
+#[test]
+fn bgr555() {
+    // 0b0_00001_10000_11111
+    assert_eq!(
+        first_bytes(PixelFormat::Bgr555, 4),
+        [0x1F, 0x06, 0x00, 0x00]
+    );
+}
+
+#[test]
+fn shade2bpp() {
+    assert_eq!(
+        first_bytes(PixelFormat::Shade2bpp, 3),
+        [0b00_01_10_11, 0b11_11_00_00, 0x00]
+    );
+}
+
+#[test]
+#[should_panic]
+fn buffer_of_another_format_is_rejected() {
+    let mut pixels = vec![0; PixelFormat::Rgb565.screen_size()];
+    Ppu::new().copy_screen_pixels(PixelFormat::Rgba8888, &mut pixels);
+}
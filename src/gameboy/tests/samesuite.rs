@@ -0,0 +1,189 @@
+#[macro_use]
+mod common;
+
+// SameSuite test roms report the result in the registers like mooneye test roms, after sending it through the serial port.
+// APU tests are ignored as there is no APU emulation.
+// DMA and PPU tests in the repository test CGB registers and fail on DMG, SGB tests need the SGB commands.
+create_mooneye_tests!(
+    apu in "samesuite/apu",
+    #[ignore = "no APU"]
+    div_trigger_volume_10,
+    #[ignore = "no APU"]
+    div_write_trigger,
+    #[ignore = "no APU"]
+    div_write_trigger_10,
+    #[ignore = "no APU"]
+    div_write_trigger_volume,
+    #[ignore = "no APU"]
+    div_write_trigger_volume_10
+);
+
+create_mooneye_tests!(
+    channel_1 in "samesuite/apu/channel_1",
+    #[ignore = "no APU"]
+    channel_1_align,
+    #[ignore = "no APU"]
+    channel_1_align_cpu,
+    #[ignore = "no APU"]
+    channel_1_delay,
+    #[ignore = "no APU"]
+    channel_1_duty,
+    #[ignore = "no APU"]
+    channel_1_duty_delay,
+    #[ignore = "no APU"]
+    channel_1_extra_length_clocking_cgb0b in "channel_1_extra_length_clocking-cgb0B",
+    #[ignore = "no APU"]
+    channel_1_freq_change,
+    #[ignore = "no APU"]
+    channel_1_freq_change_timing_a in "channel_1_freq_change_timing-A",
+    #[ignore = "no APU"]
+    channel_1_freq_change_timing_cgb0bc in "channel_1_freq_change_timing-cgb0BC",
+    #[ignore = "no APU"]
+    channel_1_freq_change_timing_cgbde in "channel_1_freq_change_timing-cgbDE",
+    #[ignore = "no APU"]
+    channel_1_nrx2_glitch,
+    #[ignore = "no APU"]
+    channel_1_nrx2_speed_change,
+    #[ignore = "no APU"]
+    channel_1_restart,
+    #[ignore = "no APU"]
+    channel_1_restart_nrx2_glitch,
+    #[ignore = "no APU"]
+    channel_1_stop_div,
+    #[ignore = "no APU"]
+    channel_1_stop_restart,
+    #[ignore = "no APU"]
+    channel_1_sweep,
+    #[ignore = "no APU"]
+    channel_1_sweep_restart,
+    #[ignore = "no APU"]
+    channel_1_sweep_restart_2,
+    #[ignore = "no APU"]
+    channel_1_volume,
+    #[ignore = "no APU"]
+    channel_1_volume_div
+);
+
+create_mooneye_tests!(
+    channel_2 in "samesuite/apu/channel_2",
+    #[ignore = "no APU"]
+    channel_2_align,
+    #[ignore = "no APU"]
+    channel_2_align_cpu,
+    #[ignore = "no APU"]
+    channel_2_delay,
+    #[ignore = "no APU"]
+    channel_2_duty,
+    #[ignore = "no APU"]
+    channel_2_duty_delay,
+    #[ignore = "no APU"]
+    channel_2_extra_length_clocking_cgb0b in "channel_2_extra_length_clocking-cgb0B",
+    #[ignore = "no APU"]
+    channel_2_freq_change,
+    #[ignore = "no APU"]
+    channel_2_nrx2_glitch,
+    #[ignore = "no APU"]
+    channel_2_nrx2_speed_change,
+    #[ignore = "no APU"]
+    channel_2_restart,
+    #[ignore = "no APU"]
+    channel_2_restart_nrx2_glitch,
+    #[ignore = "no APU"]
+    channel_2_stop_div,
+    #[ignore = "no APU"]
+    channel_2_stop_restart,
+    #[ignore = "no APU"]
+    channel_2_volume,
+    #[ignore = "no APU"]
+    channel_2_volume_div
+);
+
+create_mooneye_tests!(
+    channel_3 in "samesuite/apu/channel_3",
+    #[ignore = "no APU"]
+    channel_3_and_glitch,
+    #[ignore = "no APU"]
+    channel_3_delay,
+    #[ignore = "no APU"]
+    channel_3_extra_length_clocking_cgb0 in "channel_3_extra_length_clocking-cgb0",
+    #[ignore = "no APU"]
+    channel_3_extra_length_clocking_cgbb in "channel_3_extra_length_clocking-cgbB",
+    #[ignore = "no APU"]
+    channel_3_first_sample,
+    #[ignore = "no APU"]
+    channel_3_freq_change_delay,
+    #[ignore = "no APU"]
+    channel_3_restart_delay,
+    #[ignore = "no APU"]
+    channel_3_restart_during_delay,
+    #[ignore = "no APU"]
+    channel_3_restart_stop_delay,
+    #[ignore = "no APU"]
+    channel_3_shift_delay,
+    #[ignore = "no APU"]
+    channel_3_shift_skip_delay,
+    #[ignore = "no APU"]
+    channel_3_stop_delay,
+    #[ignore = "no APU"]
+    channel_3_stop_div,
+    #[ignore = "no APU"]
+    channel_3_wave_ram_locked_write,
+    #[ignore = "no APU"]
+    channel_3_wave_ram_sync
+);
+
+create_mooneye_tests!(
+    channel_4 in "samesuite/apu/channel_4",
+    #[ignore = "no APU"]
+    channel_4_align,
+    #[ignore = "no APU"]
+    channel_4_delay,
+    #[ignore = "no APU"]
+    channel_4_equivalent_frequencies,
+    #[ignore = "no APU"]
+    channel_4_extra_length_clocking_cgb0b in "channel_4_extra_length_clocking-cgb0B",
+    #[ignore = "no APU"]
+    channel_4_freq_change,
+    #[ignore = "no APU"]
+    channel_4_frequency_alignment,
+    #[ignore = "no APU"]
+    channel_4_lfsr,
+    #[ignore = "no APU"]
+    channel_4_lfsr15,
+    #[ignore = "no APU"]
+    channel_4_lfsr_15_7,
+    #[ignore = "no APU"]
+    channel_4_lfsr_7_15,
+    #[ignore = "no APU"]
+    channel_4_lfsr_restart,
+    #[ignore = "no APU"]
+    channel_4_lfsr_restart_fast,
+    #[ignore = "no APU"]
+    channel_4_volume_div
+);
+
+create_mooneye_tests!(
+    dma in "samesuite/dma",
+    #[ignore = "CGB only, writes the HDMA registers"]
+    gbc_dma_cont,
+    #[ignore = "CGB only, GDMA address masks"]
+    gdma_addr_mask,
+    #[ignore = "CGB only, HDMA while the LCD is off"]
+    hdma_lcd_off,
+    #[ignore = "CGB only, HDMA started in mode 0"]
+    hdma_mode0
+);
+
+create_mooneye_tests!(
+    ppu in "samesuite/ppu",
+    #[ignore = "CGB only, BGPI increment while the palette memory is blocked"]
+    blocking_bgpi_increase
+);
+
+create_mooneye_tests!(
+    sgb in "samesuite/sgb",
+    #[ignore = "SGB only, MLT_REQ command"]
+    command_mlt_req,
+    #[ignore = "SGB only, MLT_REQ player increment"]
+    command_mlt_req_1_incrementing
+);
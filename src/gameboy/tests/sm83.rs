@@ -0,0 +1,222 @@
+/*
+    Runs the SM83 single step tests, a JSON file of test cases for every opcode:
+    https://github.com/SingleStepTests/sm83
+
+    Test vectors are not in the repository, so the test is ignored by default. SM83_TESTS_PATH must be
+    set to the directory of the vectors, or they must be copied to roms/test/sm83:
+        SM83_TESTS_PATH=/path/to/sm83/v1 cargo test --test sm83 -- --ignored
+    The test fails when the directory is not found.
+
+    Every test case runs an instruction on a flat 64KB bus and compares the registers, the memory
+    and the bus activity of every M-cycle. The opcode is already fetched in the initial state,
+    the instruction ends with the fetch of the next opcode.
+*/
+
+use gameboy::{
+    bus::{Bus, BusCycle, FlatBus},
+    cpu::Cpu,
+    instructions::{INSTRUCTIONS, PREFIX_CB_INSTRUCTIONS},
+};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+const DEFAULT_TESTS_PATH: &str = "../../roms/test/sm83";
+
+#[derive(Deserialize)]
+struct TestCase {
+    name: String,
+    initial: CpuState,
+    #[serde(rename = "final")]
+    expected: CpuState,
+    cycles: Vec<Option<PinState>>,
+}
+
+// Address, data and the read, write and memory request pins of an M-cycle.
+type PinState = (Option<u16>, Option<u8>, String);
+
+#[derive(Deserialize)]
+struct CpuState {
+    pc: u16,
+    sp: u16,
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+    f: u8,
+    h: u8,
+    l: u8,
+    #[serde(default)]
+    ime: Option<u8>,
+    #[serde(default)]
+    ie: Option<u8>,
+    ram: Vec<(u16, u8)>,
+}
+
+#[test]
+#[ignore = "needs SM83 vectors"]
+fn sm83_single_step() {
+    let tests_path: PathBuf = std::env::var_os("SM83_TESTS_PATH")
+        .map(Into::into)
+        .unwrap_or_else(|| DEFAULT_TESTS_PATH.into());
+
+    assert!(
+        tests_path.is_dir(),
+        "SM83 test vectors are not found in {}, set SM83_TESTS_PATH to their directory.",
+        tests_path.display()
+    );
+
+    let mut failures = Vec::new();
+    let mut missing = Vec::new();
+    let (mut passed, mut failed) = (0, 0);
+
+    // Undefined opcodes don't have test files, prefixed opcodes are tested with their prefix.
+    let opcodes = INSTRUCTIONS
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| !matches!(instruction.name, "UNDEFINED" | "PREFIX CB"))
+        .map(|(opcode, _)| format!("{opcode:02x}"))
+        .chain((0..PREFIX_CB_INSTRUCTIONS.len()).map(|opcode| format!("cb {opcode:02x}")));
+
+    for opcode in opcodes {
+        let path = tests_path.join(format!("{opcode}.json"));
+
+        if !path.exists() {
+            missing.push(opcode);
+            continue;
+        }
+
+        let test_cases = read_test_cases(&path);
+        let mut first_failure = None;
+
+        for test_case in &test_cases {
+            match run_test_case(test_case) {
+                Ok(()) => passed += 1,
+                Err(message) => {
+                    failed += 1;
+                    first_failure.get_or_insert(format!("{}: {message}", test_case.name));
+                }
+            }
+        }
+
+        // A failing opcode usually fails most of its cases, only the first one is reported.
+        failures.extend(first_failure);
+    }
+
+    for failure in &failures {
+        println!("{failure}");
+    }
+    if !missing.is_empty() {
+        println!("Missing test files: {}", missing.join(", "));
+    }
+    println!(
+        "{passed} test cases passed, {failed} failed in {} opcodes.",
+        failures.len()
+    );
+
+    assert!(failures.is_empty(), "{} opcodes failed.", failures.len());
+}
+
+fn read_test_cases(path: &Path) -> Vec<TestCase> {
+    let file = std::fs::File::open(path).unwrap();
+
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .unwrap_or_else(|error| panic!("Cannot parse {}: {error}", path.display()))
+}
+
+fn run_test_case(test_case: &TestCase) -> Result<(), String> {
+    let initial = &test_case.initial;
+    let expected = &test_case.expected;
+
+    let mut bus = FlatBus::new();
+    for &(address, value) in &initial.ram {
+        bus.memory[address as usize] = value;
+    }
+    if let Some(ie) = initial.ie {
+        bus.memory[0xFFFF] = ie;
+    }
+
+    // Opcode is fetched again at PC - 1, its read is dropped from the recorded cycles.
+    let mut cpu = Cpu::new();
+    cpu.pc = initial.pc.wrapping_sub(1);
+    cpu.sp = initial.sp;
+    cpu.ime = initial.ime == Some(1);
+    cpu.registers
+        .set_af(u16::from_be_bytes([initial.a, initial.f]));
+    cpu.registers.b = initial.b;
+    cpu.registers.c = initial.c;
+    cpu.registers.d = initial.d;
+    cpu.registers.e = initial.e;
+    cpu.registers.h = initial.h;
+    cpu.registers.l = initial.l;
+
+    // Internal M-cycles at the end of the instruction are left to the caller, like in the emulator.
+    let clock_cycles = cpu.clock_cycles;
+    cpu.cycle(&mut bus);
+    let m_cycles = cpu.clock_cycles.wrapping_sub(clock_cycles) as usize / 4;
+    while bus.cycles.len() < m_cycles {
+        bus.tick();
+    }
+
+    // Fetch of the next opcode.
+    bus.read(cpu.pc);
+    cpu.pc = cpu.pc.wrapping_add(1);
+
+    let registers = &cpu.registers;
+    let actual = [
+        ("A", registers.a as u16, expected.a as u16),
+        ("F", registers.af() & 0xFF, expected.f as u16),
+        ("B", registers.b as u16, expected.b as u16),
+        ("C", registers.c as u16, expected.c as u16),
+        ("D", registers.d as u16, expected.d as u16),
+        ("E", registers.e as u16, expected.e as u16),
+        ("H", registers.h as u16, expected.h as u16),
+        ("L", registers.l as u16, expected.l as u16),
+        ("PC", cpu.pc, expected.pc),
+        ("SP", cpu.sp, expected.sp),
+    ];
+    for (name, value, expected) in actual {
+        if value != expected {
+            return Err(format!("{name} is {value:#X} instead of {expected:#X}."));
+        }
+    }
+
+    if let Some(ime) = expected.ime {
+        if cpu.ime != (ime == 1) {
+            return Err(format!("IME is {} instead of {ime}.", cpu.ime as u8));
+        }
+    }
+
+    for &(address, value) in &expected.ram {
+        let actual = bus.memory[address as usize];
+        if actual != value {
+            return Err(format!(
+                "Memory at {address:#06X} is {actual:#04X} instead of {value:#04X}."
+            ));
+        }
+    }
+
+    // Address and data pins are not meaningful in the internal M-cycles, only the accesses are compared.
+    let expected_cycles: Vec<BusCycle> = test_case
+        .cycles
+        .iter()
+        .map(|cycle| match cycle {
+            Some((Some(address), Some(value), pins)) if pins.contains('r') => {
+                BusCycle::Read(*address, *value)
+            }
+            Some((Some(address), Some(value), pins)) if pins.contains('w') => {
+                BusCycle::Write(*address, *value)
+            }
+            _ => BusCycle::Idle,
+        })
+        .collect();
+
+    let actual_cycles = &bus.cycles[1..];
+    if actual_cycles != expected_cycles {
+        return Err(format!(
+            "Bus cycles are {actual_cycles:X?} instead of {expected_cycles:X?}."
+        ));
+    }
+
+    Ok(())
+}
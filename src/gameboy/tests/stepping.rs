@@ -0,0 +1,109 @@
+/*
+    Tests of the stepping APIs of the emulator: run_frame and run_cycles.
+    They run the games in the roms directory, their results don't depend on the screen.
+*/
+
+use gameboy::Gameboy;
+use std::{cell::Cell, io::Write, rc::Rc};
+
+const GAME_PATH: &str = "../../roms/mario.gb";
+
+// CALL takes the longest of the instructions.
+const MAX_INSTRUCTION_CYCLES: u64 = 24;
+
+// Odd step lengths, so the steps end in the middle of instructions, scanlines and frames.
+const STEPS: [u64; 7] = [1, 3, 7, 456, 1000, 12_345, 70_224];
+
+// Counts the lines of the cpu trace, it writes a line for every executed instruction.
+struct LineCounter(Rc<Cell<u64>>);
+
+impl Write for LineCounter {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        let lines = buffer.iter().filter(|&&byte| byte == b'\n').count();
+        self.0.set(self.0.get() + lines as u64);
+
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Game halts the cpu while it waits for the VBlank, halted M-cycles are not instructions.
+#[test]
+fn run_frame_counts_executed_instructions() {
+    let mut emulator = Gameboy::after_boot();
+    emulator.load_cartidge(GAME_PATH).unwrap();
+
+    for _ in 0..60 {
+        emulator.run_frame();
+    }
+
+    let lines = Rc::new(Cell::new(0));
+    emulator.set_cpu_trace(LineCounter(lines.clone()));
+
+    let mut instructions = 0;
+    for _ in 0..60 {
+        let stats = emulator.run_frame();
+        assert!(stats.completed);
+
+        instructions += stats.instructions as u64;
+    }
+
+    assert_eq!(instructions, lines.get());
+}
+
+// Emulator state that is compared after running the same number of cycles in different steps.
+fn state(emulator: &Gameboy) -> (Vec<u64>, Vec<u8>) {
+    let registers = &emulator.cpu.registers;
+    let counters = vec![
+        emulator.total_cycles(),
+        emulator.cpu.clock_cycles as u64,
+        emulator.cpu.pc as u64,
+        emulator.cpu.sp as u64,
+        registers.af() as u64,
+        registers.bc() as u64,
+        registers.de() as u64,
+        registers.hl() as u64,
+        emulator.ppu.clock_cycles as u64,
+        emulator.ppu.frame_count(),
+    ];
+    let memory = (0..=0xFFFF)
+        .map(|address| emulator.memory_map.get(address))
+        .chain(emulator.ppu.shade_buffer.iter().copied())
+        .collect();
+
+    (counters, memory)
+}
+
+// Cpu clock starts right before it overflows, it wraps around in the middle of the run.
+#[test]
+fn run_cycles_in_steps_matches_single_call() {
+    let cycles = 5 * 4_194_304;
+
+    let mut emulators = [Gameboy::after_boot(), Gameboy::after_boot()];
+    for emulator in &mut emulators {
+        emulator.load_cartidge(GAME_PATH).unwrap();
+        emulator.cpu.clock_cycles = u32::MAX - 100;
+    }
+    let [single, stepped] = &mut emulators;
+
+    single.run_cycles(cycles);
+
+    let mut remaining = cycles;
+    for step in STEPS.iter().cycle() {
+        let step = (*step).min(remaining);
+        stepped.run_cycles(step);
+
+        remaining -= step;
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    // Run stops after the instruction that reaches the cycles, it can run past them by an instruction.
+    assert!(single.total_cycles() >= cycles);
+    assert!(single.total_cycles() < cycles + MAX_INSTRUCTION_CYCLES);
+    assert!(state(single) == state(stepped));
+}
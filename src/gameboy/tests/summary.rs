@@ -0,0 +1,153 @@
+// Only the harness of the common module is used here, the test macros are not.
+#[allow(dead_code)]
+mod common;
+
+use common::Completion;
+use std::{
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+};
+
+const TEST_ROMS_PATH: &str = "../../roms/test";
+
+// Runs every test rom and prints a pass/fail matrix of the suites.
+// Roms that are ignored in the other tests are included, so it shows the overall accuracy.
+// Run it with `cargo test --release --test summary -- --ignored --nocapture`.
+#[test]
+#[ignore = "runs every test rom, takes a few minutes"]
+fn summary() {
+    let suite_paths: Vec<PathBuf> = sorted_entries(Path::new(TEST_ROMS_PATH))
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .collect();
+
+    // Emulator panics are reported as failures, without printing them in the middle of the results.
+    let panic_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+
+    // Suites run in parallel, their results are printed in order.
+    let suites: Vec<SuiteResult> = std::thread::scope(|scope| {
+        let threads: Vec<_> = suite_paths
+            .iter()
+            .map(|path| scope.spawn(|| run_suite(path)))
+            .collect();
+
+        threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect()
+    });
+
+    std::panic::set_hook(panic_hook);
+
+    for suite in &suites {
+        print!("{}", suite.log);
+    }
+
+    println!();
+    println!(
+        "{:<24} {:>7} {:>7} {:>7}",
+        "Suite", "Passed", "Failed", "Total"
+    );
+    for suite in &suites {
+        println!(
+            "{:<24} {:>7} {:>7} {:>7}",
+            suite.name,
+            suite.passed,
+            suite.failed,
+            suite.passed + suite.failed
+        );
+    }
+
+    let passed: usize = suites.iter().map(|suite| suite.passed).sum();
+    let failed: usize = suites.iter().map(|suite| suite.failed).sum();
+    println!(
+        "{:<24} {passed:>7} {failed:>7} {:>7}",
+        "All",
+        passed + failed
+    );
+}
+
+struct SuiteResult {
+    name: String,
+    passed: usize,
+    failed: usize,
+    log: String, // A line for each rom.
+}
+
+fn run_suite(suite_path: &Path) -> SuiteResult {
+    let mut suite = SuiteResult {
+        name: suite_path.file_name().unwrap().to_str().unwrap().to_owned(),
+        passed: 0,
+        failed: 0,
+        log: String::new(),
+    };
+
+    for path in test_roms(suite_path) {
+        let name = path.strip_prefix(TEST_ROMS_PATH).unwrap().display();
+        let completion = completion(&path);
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            common::check_test_rom(&path, completion)
+        }))
+        .unwrap_or_else(|payload| {
+            let message = payload
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| payload.downcast_ref::<&str>().copied())
+                .unwrap_or("unknown error");
+            Err(format!("Emulator panicked: {message}"))
+        });
+
+        match result {
+            Ok(cycles) => {
+                suite.log += &format!("PASS {name} ({cycles} cycles)\n");
+                suite.passed += 1;
+            }
+            Err(message) => {
+                suite.log += &format!("FAIL {name}: {message}\n");
+                suite.failed += 1;
+            }
+        }
+    }
+
+    suite
+}
+
+// Each suite reports its results in the same way as in its test module.
+fn completion(path: &Path) -> Completion {
+    let path = path.to_str().unwrap();
+
+    if path.contains("mealybug") || path.contains("manual-only") {
+        Completion::LdBB
+    } else if path.contains("mooneye") || path.contains("samesuite") {
+        Completion::Fibonacci
+    } else {
+        Completion::InfiniteLoop
+    }
+}
+
+// Finds the roms in a directory and its subdirectories.
+fn test_roms(path: &Path) -> Vec<PathBuf> {
+    let mut roms = Vec::new();
+
+    for path in sorted_entries(path) {
+        if path.is_dir() {
+            roms.extend(test_roms(&path));
+        } else if path.extension().and_then(|extension| extension.to_str()) == Some("gb") {
+            roms.push(path);
+        }
+    }
+
+    roms
+}
+
+fn sorted_entries(path: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(path)
+        .unwrap()
+        .map(|dir_entry| dir_entry.unwrap().path())
+        .collect();
+    entries.sort();
+
+    entries
+}
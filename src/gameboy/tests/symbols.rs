@@ -0,0 +1,110 @@
+/*
+    Tests of parsing symbol files and of the labels they give to the disassembly.
+*/
+
+use gameboy::{symbols::Symbols, Gameboy};
+
+const ROM_PATH: &str = "../../roms/test/blargg/cpu_instrs/06-ld_r,r.gb";
+
+#[test]
+fn labels_have_a_bank_and_an_address() {
+    let symbols = Symbols::parse("00:0150 Start\n01:4000 LoadLevel\n1F:7FFF LastByte\n");
+
+    assert_eq!(symbols.len(), 3);
+    assert_eq!(symbols.label(0x00, 0x0150), Some("Start"));
+    assert_eq!(symbols.label(0x01, 0x4000), Some("LoadLevel"));
+    assert_eq!(symbols.label(0x1F, 0x7FFF), Some("LastByte"));
+    assert_eq!(symbols.find("LoadLevel"), Some((0x01, 0x4000)));
+
+    // Same address in another bank is another label.
+    assert_eq!(symbols.label(0x02, 0x4000), None);
+}
+
+#[test]
+fn comments_and_blank_lines_are_skipped() {
+    let symbols = Symbols::parse(
+        "; File generated by rgblink\n\
+         \n\
+         00:0150 Start ; entry point\n\
+         \x20\x20\n\
+         ;00:0160 Commented\n",
+    );
+
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols.label(0x00, 0x0150), Some("Start"));
+    assert_eq!(symbols.find("Commented"), None);
+}
+
+#[test]
+fn local_labels_keep_their_full_name() {
+    let symbols = Symbols::parse("00:0150 Start\n00:0155 Start.loop\n00:0160 .done\n");
+
+    assert_eq!(symbols.label(0x00, 0x0155), Some("Start.loop"));
+    assert_eq!(symbols.label(0x00, 0x0160), Some(".done"));
+    assert_eq!(symbols.find("Start.loop"), Some((0x00, 0x0155)));
+}
+
+#[test]
+fn first_label_of_an_address_is_shown() {
+    let symbols = Symbols::parse("00:0150 Start\n00:0150 Init\n");
+
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols.label(0x00, 0x0150), Some("Start"));
+    assert_eq!(symbols.find("Init"), Some((0x00, 0x0150)));
+}
+
+#[test]
+fn malformed_lines_are_skipped() {
+    let symbols = Symbols::parse(
+        "0150 NoBank\n\
+         zz:0150 BadBank\n\
+         00:wxyz BadAddress\n\
+         00:10000 AddressTooLarge\n\
+         00:0150\n\
+         00:0151 Non\u{e9}Ascii\n\
+         00:0152 Good\n",
+    );
+
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols.label(0x00, 0x0152), Some("Good"));
+    assert_eq!(symbols.label(0x00, 0x0150), None);
+    assert_eq!(symbols.label(0x00, 0x0151), None);
+}
+
+// Writes the instruction at the address with its jump or call target written as a label, like the debugger does.
+fn disassemble_with_labels(emulator: &Gameboy, address: u16) -> String {
+    let instruction = emulator.disassemble(address);
+    let target_label = instruction
+        .target()
+        .and_then(|target| emulator.label_at(target));
+
+    let mut text = String::new();
+    instruction.write_rgbds(&mut text, target_label).unwrap();
+    text
+}
+
+#[test]
+fn disassembly_uses_the_loaded_labels() {
+    let mut emulator = Gameboy::after_boot();
+    emulator.load_cartidge(ROM_PATH).unwrap();
+    emulator.symbols = Symbols::parse(
+        "00:0200 CopyBank\n\
+         00:0206 CopyBank.loop\n\
+         00:0213 Main\n\
+         01:4000 BankStart\n\
+         00:4000 NotMapped\n",
+    );
+
+    assert_eq!(disassemble_with_labels(&emulator, 0x0101), "jp Main");
+    assert_eq!(
+        disassemble_with_labels(&emulator, 0x0209),
+        "jr nz, CopyBank.loop"
+    );
+    assert_eq!(disassemble_with_labels(&emulator, 0x0216), "jp CopyBank");
+
+    // Switchable ROM bank uses the label of the mapped bank only.
+    assert_eq!(emulator.label_at(0x4000), Some("BankStart"));
+
+    // Instructions without a target are written without labels.
+    assert_eq!(disassemble_with_labels(&emulator, 0x0213), "ld hl, $4000");
+}
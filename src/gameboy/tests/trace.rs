@@ -0,0 +1,66 @@
+/*
+    Tests of the cpu trace against lines in the Gameboy Doctor format.
+    Expected lines start at the documented boot-skipped state and follow the first instructions of the rom:
+        0100 NOP
+        0101 JP $0213
+        0213 LD HL,$4000
+        0216 JP $0200
+        0200 LD B,A; LD DE,$C000; LD C,$10
+        0206 LD A,(HL+); LD (DE),A; INC E; JR NZ,$0206
+*/
+
+use gameboy::Gameboy;
+use std::{cell::RefCell, io::Write, rc::Rc};
+
+const ROM_PATH: &str = "../../roms/test/blargg/cpu_instrs/06-ld_r,r.gb";
+
+const EXPECTED_LINES: [&str; 13] = [
+    "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0100 PCMEM:00,C3,13,02",
+    "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0101 PCMEM:C3,13,02,CE",
+    "A:01 F:B0 B:00 C:13 D:00 E:D8 H:01 L:4D SP:FFFE PC:0213 PCMEM:21,00,40,C3",
+    "A:01 F:B0 B:00 C:13 D:00 E:D8 H:40 L:00 SP:FFFE PC:0216 PCMEM:C3,00,02,00",
+    "A:01 F:B0 B:00 C:13 D:00 E:D8 H:40 L:00 SP:FFFE PC:0200 PCMEM:47,11,00,C0",
+    "A:01 F:B0 B:01 C:13 D:00 E:D8 H:40 L:00 SP:FFFE PC:0201 PCMEM:11,00,C0,0E",
+    "A:01 F:B0 B:01 C:13 D:C0 E:00 H:40 L:00 SP:FFFE PC:0204 PCMEM:0E,10,2A,12",
+    "A:01 F:B0 B:01 C:10 D:C0 E:00 H:40 L:00 SP:FFFE PC:0206 PCMEM:2A,12,1C,20",
+    "A:C3 F:B0 B:01 C:10 D:C0 E:00 H:40 L:01 SP:FFFE PC:0207 PCMEM:12,1C,20,FB",
+    "A:C3 F:B0 B:01 C:10 D:C0 E:00 H:40 L:01 SP:FFFE PC:0208 PCMEM:1C,20,FB,14",
+    // INC E clears Z, N and H and keeps C.
+    "A:C3 F:10 B:01 C:10 D:C0 E:01 H:40 L:01 SP:FFFE PC:0209 PCMEM:20,FB,14,0D",
+    "A:C3 F:10 B:01 C:10 D:C0 E:01 H:40 L:01 SP:FFFE PC:0206 PCMEM:2A,12,1C,20",
+    "A:20 F:10 B:01 C:10 D:C0 E:01 H:40 L:02 SP:FFFE PC:0207 PCMEM:12,1C,20,FB",
+];
+
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buffer);
+
+        Ok(buffer.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn trace_matches_gameboy_doctor_lines() {
+    let mut emulator = Gameboy::after_boot();
+    emulator.load_cartidge(ROM_PATH).unwrap();
+    emulator.memory_map.ly_override = Some(0x90);
+
+    let trace = Rc::new(RefCell::new(Vec::new()));
+    emulator.set_cpu_trace(SharedBuffer(trace.clone()));
+
+    for _ in 0..EXPECTED_LINES.len() {
+        emulator.cycle_once();
+    }
+    emulator.clear_cpu_trace();
+
+    let trace = String::from_utf8(trace.take()).unwrap();
+    let lines: Vec<&str> = trace.lines().collect();
+
+    assert_eq!(lines, EXPECTED_LINES);
+}
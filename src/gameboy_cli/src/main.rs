@@ -0,0 +1,357 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use gameboy::{
+    disassembler,
+    ppu::{self, PixelFormat},
+    Gameboy,
+};
+
+const USAGE: &str = "\
+Runs a rom without a window and reports the results.
+
+Usage: gameboy_cli <rom> [options]
+
+Options:
+    --boot-rom <path>           Boot from the given boot rom instead of starting after boot.
+    --model <model>             Emulated model. Only dmg is supported.
+    --frames <count>            Stop after the given number of frames.
+                                While the LCD is off, the duration of a frame counts as a frame.
+    --cycles <count>            Stop after the given number of T-cycles.
+    --seconds <seconds>         Stop after the given emulated time. Defaults to 30 seconds.
+    --until <condition>         Stop early when the rom finishes:
+                                    ld-b-b  Rom executes the LD B,B debug breakpoint (mooneye).
+                                    loop    Rom jumps to itself in an infinite loop.
+    --screenshot <path>         Write the screen as a PNG on exit.
+    --serial <path>             Write the serial output on exit.
+    --ram-dump <path>           Write the entire address space on exit.
+    --trace <path>              Write a Gameboy Doctor log of the cpu instructions.
+                                LY reads as 0x90 like in the known-good logs.
+    --disassemble <directory>   Write an RGBDS project of the rom to the directory instead of running it.
+    --expect-serial <text>      Succeed only if the serial output contains the text.
+    --expect-screenshot <path>  Succeed only if the screen matches the PNG.
+    --expect-fibonacci          Succeed only if the registers hold the mooneye pass values.
+
+Exit codes:
+    0  Every expectation is met.
+    1  An expectation is not met.
+    2  Invalid arguments, an IO error or an invalid or unsupported cartridge.
+    3  The --until condition is not reached in the time limit.";
+
+// Grayscale colors that are used in the reference images of the test roms.
+const COLOR_SHADES: [u32; 4] = [0xFFFFFFFF, 0xFFAAAAAA, 0xFF555555, 0xFF000000];
+
+// Mooneye test roms load the fibonacci numbers to the registers when they pass.
+const FIBONACCI_REGISTERS: [u8; 6] = [3, 5, 8, 13, 21, 34];
+
+#[derive(Clone, Copy)]
+enum Limit {
+    Frames(u64),
+    Cycles(u64),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Until {
+    LdBB,
+    InfiniteLoop,
+}
+
+struct Options {
+    rom: PathBuf,
+    boot_rom: Option<PathBuf>,
+    limit: Limit,
+    until: Option<Until>,
+
+    screenshot: Option<PathBuf>,
+    serial: Option<PathBuf>,
+    ram_dump: Option<PathBuf>,
+    trace: Option<PathBuf>,
+    disassemble: Option<PathBuf>,
+
+    expect_serial: Option<String>,
+    expect_screenshot: Option<PathBuf>,
+    expect_fibonacci: bool,
+}
+
+impl Options {
+    fn parse(mut arguments: impl Iterator<Item = String>) -> Result<Self, Box<dyn Error>> {
+        let mut rom = None;
+        let mut options = Self {
+            rom: PathBuf::new(),
+            boot_rom: None,
+            limit: Limit::Cycles(30 * ppu::PPU_CLOCK_RATE as u64),
+            until: None,
+
+            screenshot: None,
+            serial: None,
+            ram_dump: None,
+            trace: None,
+            disassemble: None,
+
+            expect_serial: None,
+            expect_screenshot: None,
+            expect_fibonacci: false,
+        };
+
+        while let Some(argument) = arguments.next() {
+            let mut value = || {
+                arguments
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {argument}."))
+            };
+
+            match argument.as_str() {
+                "--boot-rom" => options.boot_rom = Some(value()?.into()),
+                "--model" => {
+                    let model = value()?;
+                    if !model.eq_ignore_ascii_case("dmg") {
+                        return Err(format!("Model {model} is not supported.").into());
+                    }
+                }
+                "--frames" => options.limit = Limit::Frames(value()?.parse()?),
+                "--cycles" => options.limit = Limit::Cycles(value()?.parse()?),
+                "--seconds" => {
+                    let seconds: f64 = value()?.parse()?;
+                    options.limit = Limit::Cycles((seconds * ppu::PPU_CLOCK_RATE as f64) as u64);
+                }
+                "--until" => {
+                    options.until = Some(match value()?.as_str() {
+                        "ld-b-b" => Until::LdBB,
+                        "loop" => Until::InfiniteLoop,
+                        condition => return Err(format!("Unknown condition {condition}.").into()),
+                    })
+                }
+                "--screenshot" => options.screenshot = Some(value()?.into()),
+                "--serial" => options.serial = Some(value()?.into()),
+                "--ram-dump" => options.ram_dump = Some(value()?.into()),
+                "--trace" => options.trace = Some(value()?.into()),
+                "--disassemble" => options.disassemble = Some(value()?.into()),
+                "--expect-serial" => options.expect_serial = Some(value()?),
+                "--expect-screenshot" => options.expect_screenshot = Some(value()?.into()),
+                "--expect-fibonacci" => options.expect_fibonacci = true,
+                _ if argument.starts_with("--") => {
+                    return Err(format!("Unknown option {argument}.").into())
+                }
+                _ if rom.is_none() => rom = Some(argument.into()),
+                _ => return Err(format!("Unexpected argument {argument}.").into()),
+            }
+        }
+
+        options.rom = rom.ok_or("Missing rom path.")?;
+
+        Ok(options)
+    }
+}
+
+fn main() -> ExitCode {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+
+    if arguments.is_empty() || arguments.iter().any(|argument| argument == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    let options = match Options::parse(arguments.into_iter()) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("Error: {error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(options: &Options) -> Result<ExitCode, Box<dyn Error>> {
+    if !options.rom.is_file() {
+        return Err(format!("Rom {} doesn't exist.", options.rom.display()).into());
+    }
+
+    if let Some(directory) = &options.disassemble {
+        let rom = std::fs::read(&options.rom)?;
+        let name = options.rom.file_stem().unwrap().to_string_lossy();
+        let disassembly = disassembler::disassemble_rom(&rom);
+
+        disassembly
+            .write_project(directory, &name)
+            .map_err(|error| format!("Cannot write {}: {error}", directory.display()))?;
+
+        println!(
+            "Found {} bytes of code in {} banks.",
+            disassembly.code_size(),
+            disassembly.bank_count()
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut emulator = match &options.boot_rom {
+        Some(boot_rom) => Gameboy::new(boot_rom)?,
+        None => Gameboy::after_boot(),
+    };
+
+    emulator
+        .load_cartidge(&options.rom)
+        .map_err(|error| format!("Cannot load {}: {error}", options.rom.display()))?;
+    emulator.ppu.color_shades = COLOR_SHADES;
+
+    if let Some(path) = &options.trace {
+        let file = File::create(path)
+            .map_err(|error| format!("Cannot create {}: {error}", path.display()))?;
+        emulator.set_cpu_trace(BufWriter::new(file));
+        emulator.memory_map.ly_override = Some(0x90);
+    }
+
+    let finished = run_until(&mut emulator, options.limit, options.until);
+    emulator.clear_cpu_trace();
+
+    write_outputs(&emulator, options)?;
+
+    let mut passed = true;
+
+    if let Some(text) = &options.expect_serial {
+        let serial = String::from_utf8_lossy(&emulator.memory_map.serial_output);
+        if !serial.contains(text.as_str()) {
+            println!("Serial output doesn't contain {text:?}.");
+            passed = false;
+        }
+    }
+
+    if let Some(path) = &options.expect_screenshot {
+        if screen_image(&emulator) != image::open(path)?.into_rgba8() {
+            println!("Screen doesn't match {}.", path.display());
+            passed = false;
+        }
+    }
+
+    if options.expect_fibonacci {
+        let registers = &emulator.cpu.registers;
+        let values = [
+            registers.b,
+            registers.c,
+            registers.d,
+            registers.e,
+            registers.h,
+            registers.l,
+        ];
+
+        if values != FIBONACCI_REGISTERS {
+            println!("Registers don't hold the fibonacci numbers: {values:?}.");
+            passed = false;
+        }
+    }
+
+    println!(
+        "Ran {} cycles, {} frames.",
+        emulator.total_cycles(),
+        emulator.ppu.frame_count()
+    );
+
+    Ok(if !finished {
+        println!("Rom didn't finish in the time limit.");
+        ExitCode::from(3)
+    } else if !passed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+// Runs the emulator until the condition is met or the limit is reached.
+// Returns false if there is a condition and it is not met.
+fn run_until(emulator: &mut Gameboy, limit: Limit, until: Option<Until>) -> bool {
+    let mut finished = false;
+    let mut old_pc = None;
+    let mut frames = 0;
+
+    loop {
+        // Run a frame at a time like Gameboy::run_frame, stopping at the VBlank or after the duration of a frame.
+        let mut cycles = ppu::PPU_ONE_FRAME as u64;
+        match limit {
+            Limit::Frames(limit) if frames >= limit => break,
+            Limit::Cycles(limit) if emulator.total_cycles() >= limit => break,
+            Limit::Cycles(limit) => cycles = cycles.min(limit - emulator.total_cycles()),
+            Limit::Frames(_) => {}
+        }
+
+        let start_frame = emulator.ppu.frame_count();
+
+        emulator.debug_run_cycles(cycles, |emulator| {
+            finished = match until {
+                Some(Until::LdBB) => emulator.decode_instr(emulator.cpu.pc).name == "LD B,B",
+                Some(Until::InfiniteLoop) => {
+                    // A halt with IME disabled is only waiting for an event in the middle of a test.
+                    let waiting = emulator.cpu.is_halted() && !emulator.cpu.ime;
+                    let looping = old_pc == Some(emulator.cpu.pc) && !waiting;
+
+                    old_pc = Some(emulator.cpu.pc);
+                    looping
+                }
+                None => false,
+            };
+
+            finished || emulator.ppu.frame_count() != start_frame
+        });
+
+        if finished {
+            // Finish the current frame and draw a complete one after it, like the test harness does.
+            emulator.run_frame();
+            emulator.run_frame();
+            break;
+        }
+
+        frames += 1;
+    }
+
+    finished || until.is_none()
+}
+
+fn screen_image(emulator: &Gameboy) -> image::RgbaImage {
+    let pixels = if emulator.ppu.is_display_blank() {
+        COLOR_SHADES[0]
+            .to_le_bytes()
+            .repeat(ppu::SCREEN_WIDTH * ppu::SCREEN_HEIGHT)
+    } else {
+        let mut pixels = vec![0; PixelFormat::Rgba8888.screen_size()];
+        emulator
+            .ppu
+            .copy_screen_pixels(PixelFormat::Rgba8888, &mut pixels);
+        pixels
+    };
+
+    image::RgbaImage::from_raw(ppu::SCREEN_WIDTH as _, ppu::SCREEN_HEIGHT as _, pixels).unwrap()
+}
+
+fn write_outputs(emulator: &Gameboy, options: &Options) -> Result<(), Box<dyn Error>> {
+    let write = |path: &Path, contents: &[u8]| {
+        std::fs::write(path, contents)
+            .map_err(|error| format!("Cannot write {}: {error}", path.display()))
+    };
+
+    if let Some(path) = &options.screenshot {
+        screen_image(emulator).save(path)?;
+    }
+
+    if let Some(path) = &options.serial {
+        write(path, &emulator.memory_map.serial_output)?;
+    }
+
+    if let Some(path) = &options.ram_dump {
+        let memory: Vec<u8> = (0..=0xFFFF)
+            .map(|address| emulator.memory_map.get(address))
+            .collect();
+        write(path, &memory)?;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 mod panels;
 mod renderer;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Instant;
 
@@ -9,7 +9,7 @@ use gameboy::{self, ppu};
 
 use self::panels::Panels;
 
-use gameboy::Gameboy;
+use gameboy::{symbols::Symbols, Gameboy};
 use renderer::{framebuffer::Framebuffer, Renderer};
 
 use panels::Panel;
@@ -24,6 +24,8 @@ pub struct GameboyRenderer {
     panels: Panels,
 
     renderer: Renderer,
+
+    blank_screen: Box<[u32; ppu::SCREEN_WIDTH * ppu::SCREEN_HEIGHT]>,
 }
 
 impl GameboyRenderer {
@@ -38,13 +40,16 @@ impl GameboyRenderer {
 
             panels,
             renderer,
+
+            blank_screen: Box::new([0u32; ppu::SCREEN_WIDTH * ppu::SCREEN_HEIGHT]),
         }
     }
 
     pub fn run(&mut self) {
         let emulator = &mut Gameboy::after_boot();
 
-        emulator.load_cartidge(&self.current_rom_path);
+        emulator.load_cartidge(&self.current_rom_path).unwrap();
+        load_symbols(emulator, &self.current_rom_path);
 
         self.run_with(emulator);
     }
@@ -105,7 +110,7 @@ impl GameboyRenderer {
             framebuffer.update_buffer(
                 ppu::SCREEN_WIDTH as _,
                 ppu::SCREEN_HEIGHT as _,
-                emulator.ppu.screen_buffer.as_ptr() as _,
+                self.displayed_screen(emulator).as_ptr() as _,
                 gl::RGBA8,
                 gl::RGBA,
             );
@@ -144,10 +149,18 @@ impl GameboyRenderer {
                                 FileDialog::set_directory(FileDialog::new(), "./roms").pick_file();
 
                             if let Some(file_path) = file {
-                                *emulator = Gameboy::after_boot();
-                                emulator.load_cartidge(&file_path);
-                                self.current_rom_path = file_path;
-                                reset_emulator = true;
+                                let mut loaded = Gameboy::after_boot();
+
+                                if let Err(error) = loaded.load_cartidge(&file_path) {
+                                    ui.modal_popup("Cannot load cartridge", || {
+                                        ui.text(format!("Error: {}", error));
+                                    });
+                                } else {
+                                    *emulator = loaded;
+                                    load_symbols(emulator, &file_path);
+                                    self.current_rom_path = file_path;
+                                    reset_emulator = true;
+                                }
                             }
                         }
 
@@ -158,7 +171,8 @@ impl GameboyRenderer {
                                 *emulator = Gameboy::after_boot();
                             }
 
-                            emulator.load_cartidge(&self.current_rom_path);
+                            emulator.load_cartidge(&self.current_rom_path).unwrap();
+                            load_symbols(emulator, &self.current_rom_path);
 
                             reset_emulator = true;
                         }
@@ -179,6 +193,7 @@ impl GameboyRenderer {
                         small_panel(&mut self.panels.io_map);
                         small_panel(&mut self.panels.keyboard_map);
                         small_panel(&mut self.panels.bg_map);
+                        small_panel(&mut self.panels.layers);
                     });
 
                     ui.menu("Boot Rom", || {
@@ -242,6 +257,19 @@ impl GameboyRenderer {
                             }
                         }
 
+                        if ui.menu_item("Disassemble rom") {
+                            let directory = FileDialog::new().pick_folder();
+                            if let Some(directory) = directory {
+                                if let Err(error) =
+                                    disassemble_rom(&self.current_rom_path, &directory)
+                                {
+                                    ui.modal_popup("Cannot disassemble rom", || {
+                                        ui.text(format!("Error: {}", error));
+                                    });
+                                }
+                            }
+                        }
+
                         if ui.menu_item("Dump memory") {
                             let path = FileDialog::new()
                                 .set_file_name("memory_dump.txt")
@@ -289,6 +317,16 @@ impl GameboyRenderer {
         }
     }
 
+    // LCD shows white while it's off and in the first frame after turning it on, the screen buffer is stale then.
+    fn displayed_screen<'a>(&'a mut self, emulator: &'a Gameboy) -> &'a [u32] {
+        if emulator.ppu.is_display_blank() {
+            self.blank_screen.fill(emulator.ppu.color_shades[0]);
+            &self.blank_screen[..]
+        } else {
+            &emulator.ppu.screen_buffer[..]
+        }
+    }
+
     fn run_game_mode(&mut self, emulator: &mut Gameboy, framebuffer: &Framebuffer) {
         // Resize window.
         self.renderer.resize(400, 360);
@@ -326,7 +364,7 @@ impl GameboyRenderer {
             framebuffer.update_buffer(
                 ppu::SCREEN_WIDTH as _,
                 ppu::SCREEN_HEIGHT as _,
-                emulator.ppu.screen_buffer.as_ptr() as _,
+                self.displayed_screen(emulator).as_ptr() as _,
                 gl::RGBA8,
                 gl::RGBA,
             );
@@ -341,3 +379,17 @@ impl GameboyRenderer {
         }
     }
 }
+
+// Loads the labels from the symbol file next to the rom, like the one rgblink writes with the same name.
+// Roms without a symbol file don't have labels.
+fn load_symbols(emulator: &mut Gameboy, rom_path: &Path) {
+    emulator.symbols = Symbols::load(rom_path.with_extension("sym")).unwrap_or_default();
+}
+
+// Writes an RGBDS project of the rom to the directory, named after the rom file.
+fn disassemble_rom(rom_path: &Path, directory: &Path) -> std::io::Result<()> {
+    let rom = std::fs::read(rom_path)?;
+    let name = rom_path.file_stem().unwrap().to_string_lossy();
+
+    gameboy::disassembler::disassemble_rom(&rom).write_project(directory, &name)
+}
@@ -1,23 +1,22 @@
 use std::{
     error::Error,
+    fmt::Write as _,
     fs::File,
     io::{BufWriter, Write},
     path::Path,
-    ptr::copy_nonoverlapping,
     time::Instant,
 };
 
 use imgui::StyleColor;
 use strum::IntoEnumIterator;
 
-use gameboy::{
-    instructions::{Instruction, MAX_INSTRUCTION_NAME_LENGTH},
-    memory_map::Io,
-    Gameboy,
-};
+use gameboy::{cpu::CpuState, instructions::MAX_INSTRUCTION_NAME_LENGTH, memory_map::Io, Gameboy};
 
 use super::{GoToLinePopup, Panel};
 
+// Instructions are written with the label of their address and the label of their target, long labels are cut off.
+const LINE_LENGTH: usize = 30 + MAX_INSTRUCTION_NAME_LENGTH + 64;
+
 struct DebuggerWindow {
     opened: bool,
     current_item: i32,
@@ -152,9 +151,12 @@ impl DebuggerPanel {
                 self.breakpoints
                     .iter()
                     .any(|point| point.pointer == emulator.cpu.pc)
+                    || matches!(emulator.cpu.state(), CpuState::Locked(_))
             });
 
-            if emulator.memory_map.triggered_watch.is_some() {
+            if emulator.memory_map.triggered_watch.is_some()
+                || matches!(emulator.cpu.state(), CpuState::Locked(_))
+            {
                 self.pause(emulator);
             }
 
@@ -194,9 +196,16 @@ impl DebuggerPanel {
     }
 
     pub fn pause(&mut self, emulator: &Gameboy) {
+        // Show the faulting instruction if the cpu is locked.
+        let pointer = if let CpuState::Locked(locked_pc) = emulator.cpu.state() {
+            locked_pc
+        } else {
+            emulator.cpu.pc
+        };
+
         self.toggled_breakpoint = Some(Breakpoint {
-            row: self.get_line_at_address(emulator, emulator.cpu.pc as _),
-            pointer: emulator.cpu.pc,
+            row: self.get_line_at_address(emulator, pointer as _),
+            pointer,
         });
 
         self.line_count = self.get_line_at_address(emulator, 0x10000);
@@ -220,10 +229,12 @@ impl DebuggerPanel {
         while pointer < 0x10000 {
             let instruction = emulator.decode_instr(pointer as _);
 
-            let mut buffer = [' ' as u8; 30 + MAX_INSTRUCTION_NAME_LENGTH];
-            Self::format_instruction_name(&mut buffer, emulator, instruction, pointer as _);
-            buffer[buffer.len() - 1] = '\n' as u8;
-            file.write(&buffer)?;
+            let mut buffer = [' ' as u8; LINE_LENGTH];
+            writeln!(
+                file,
+                "{}",
+                Self::format_instruction_name(&mut buffer, emulator, pointer as _).trim_end()
+            )?;
 
             pointer += instruction.length as usize;
         }
@@ -257,76 +268,31 @@ impl DebuggerPanel {
     fn format_instruction_name<'a>(
         buffer: &'a mut [u8],
         emulator: &Gameboy,
-        instruction: Instruction,
         address: u16,
     ) -> &'a str {
         assert!(buffer.len() >= 21 + MAX_INSTRUCTION_NAME_LENGTH);
 
+        let disassembly = emulator.disassemble(address);
+
         super::format_in_place(buffer, address, 0);
         buffer[4] = ':' as u8;
 
-        for i in 0..instruction.length as _ {
-            super::format_in_place(
-                buffer,
-                emulator.memory_map.get(address + i),
-                6 + i as usize * 3,
-            );
+        for (i, &byte) in disassembly.bytes.iter().enumerate() {
+            super::format_in_place(buffer, byte, 6 + i * 3);
         }
 
-        unsafe {
-            copy_nonoverlapping(
-                instruction.name.as_bytes().as_ptr(),
-                buffer.as_mut_ptr().add(21),
-                instruction.name.len(),
-            );
+        let mut text = String::new();
+        if let Some(label) = emulator.label_at(address) {
+            let _ = write!(text, "{label}: ");
         }
+        let target_label = disassembly
+            .target()
+            .and_then(|target| emulator.label_at(target));
+        let _ = disassembly.write_rgbds(&mut text, target_label);
 
-        let mut replace_signature = |signature: &'static str, bytes: u16| {
-            if let Some(signature_location) = instruction.name.find(signature) {
-                // // Instrcutions with relavite data format
-                // if instruction_name.starts_with("ADD SP") ||
-                // instruction_name.starts_with("JR") ||
-                // instruction_name.starts_with("LD HL,SP") {
-                // }
-
-                let mut data: u16 = 0;
-
-                for i in 0..bytes {
-                    data = (data << 8)
-                        | emulator
-                            .memory_map
-                            .get(address + instruction.length as u16 - i - 1)
-                            as u16;
-                }
-
-                let index = 21 + signature_location;
-
-                let difference = (bytes as usize * 2 + 2) - signature.len();
-
-                for i in (signature_location + signature.len()..instruction.name.len()).rev() {
-                    buffer[21 + i + difference] = buffer[21 + i];
-                }
-
-                buffer[index] = '0' as u8;
-                buffer[index + 1] = 'x' as u8;
-
-                if bytes == 1 {
-                    super::format_in_place::<u8>(buffer, data as _, index + 2);
-                } else {
-                    super::format_in_place(buffer, data, index + 2);
-                }
-
-                true
-            } else {
-                false
-            }
-        };
-
-        let _ = replace_signature("d16", 2)
-            || replace_signature("a16", 2)
-            || replace_signature("r8", 1)
-            || replace_signature("d8", 1)
-            || replace_signature("a8", 1);
+        // Text that doesn't fit in the buffer is cut off.
+        let length = text.len().min(buffer.len() - 21);
+        buffer[21..21 + length].copy_from_slice(&text.as_bytes()[..length]);
 
         unsafe { std::str::from_utf8_unchecked(buffer) }
     }
@@ -540,6 +506,19 @@ impl Panel for DebuggerPanel {
                             .remove(deleted_string_index);
                     }
 
+                    // Illegal opcodes hang the cpu until the emulator is reset.
+                    if let CpuState::Locked(locked_pc) = emulator.cpu.state() {
+                        ui.same_line();
+                        ui.text_colored(
+                            [1.0, 0.0, 0.0, 1.0],
+                            format!(
+                                "CPU locked: illegal opcode {:02x} at {:04x}",
+                                emulator.memory_map.get(locked_pc),
+                                locked_pc
+                            ),
+                        );
+                    }
+
                     // // Clear breakpoints button
                     // ui.same_line();
                     // if ui.button("Clear all breakpoints") {
@@ -584,6 +563,7 @@ impl Panel for DebuggerPanel {
 
                         line as f32 / self.line_count as f32
                     },
+                    |name| super::find_label(emulator, name),
                 );
 
                 // Use a list clipper for efficient rendering.
@@ -624,13 +604,12 @@ impl Panel for DebuggerPanel {
 
                     let instruction = emulator.decode_instr(current_address);
 
-                    let mut buffer = [' ' as u8; 30 + MAX_INSTRUCTION_NAME_LENGTH];
+                    let mut buffer = [' ' as u8; LINE_LENGTH];
 
                     if ui
                         .selectable_config(Self::format_instruction_name(
                             &mut buffer,
                             emulator,
-                            instruction,
                             current_address,
                         ))
                         .selected(
@@ -650,9 +629,14 @@ impl Panel for DebuggerPanel {
                                 pointer,
                             });
 
+                            let label_string = match emulator.label_at(pointer) {
+                                Some(label) => format!("({label})"),
+                                None => String::new(),
+                            };
+
                             let breakpoint_string = format!(
-                                "Line: {}, Address: {:04x}, Instruction: {}",
-                                current_row, pointer, instruction.name
+                                "Line: {}, Address: {:04x}{}, Instruction: {}",
+                                current_row, pointer, label_string, instruction.name
                             );
 
                             self.breakpoints_window.strings.push(breakpoint_string);
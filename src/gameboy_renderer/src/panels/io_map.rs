@@ -6,11 +6,15 @@ use super::Panel;
 
 pub struct IoMapPanel {
     opened: bool,
+    timing_line: u8,
 }
 
 impl IoMapPanel {
     pub fn new() -> Self {
-        Self { opened: false }
+        Self {
+            opened: false,
+            timing_line: 0,
+        }
     }
 }
 
@@ -72,6 +76,20 @@ impl Panel for IoMapPanel {
                     i += 1;
                 });
 
+                ui.separator();
+
+                // Mode timings of a scanline, useful for checking raster effects done in HBlank.
+                // They are approximate on lines with objects, the emulated object penalty isn't exact.
+                ui.set_next_item_width(50.0);
+                ui.input_scalar("Line", &mut self.timing_line).build();
+
+                if let Some(timing) = emulator.ppu.line_timing(self.timing_line) {
+                    ui.text(format!(
+                        "Mode 2: {} dots, Mode 3: ~{} dots, Mode 0: ~{} dots",
+                        timing.oam_search, timing.pixel_transfer, timing.hblank
+                    ));
+                }
+
                 true
             })
             .unwrap_or(true);
@@ -0,0 +1,131 @@
+use imgui::TextureId;
+
+use gameboy::ppu::{self, LayerBuffers};
+
+use crate::renderer::framebuffer::Framebuffer;
+
+use super::Panel;
+
+const IMAGE_SCALE: f32 = 1.5;
+
+pub struct LayersPanel {
+    opened: bool,
+
+    background: Framebuffer,
+    window: Framebuffer,
+    objects: Framebuffer,
+}
+
+impl LayersPanel {
+    pub fn new() -> Self {
+        Self {
+            opened: false,
+
+            background: Framebuffer::new(),
+            window: Framebuffer::new(),
+            objects: Framebuffer::new(),
+        }
+    }
+}
+
+impl Panel for LayersPanel {
+    fn update(&mut self, emulator: &gameboy::Gameboy) {
+        if !self.opened {
+            return;
+        }
+
+        if let Some(layer_buffers) = &emulator.ppu.layer_buffers {
+            for (framebuffer, buffer) in [
+                (&self.background, &layer_buffers.background),
+                (&self.window, &layer_buffers.window),
+                (&self.objects, &layer_buffers.objects),
+            ] {
+                framebuffer.update_buffer(
+                    ppu::SCREEN_WIDTH as _,
+                    ppu::SCREEN_HEIGHT as _,
+                    buffer.as_ptr() as _,
+                    gl::RGBA8,
+                    gl::RGBA,
+                );
+            }
+        }
+    }
+
+    fn render(&mut self, ui: &imgui::Ui, emulator: &mut gameboy::Gameboy, _: f32, _: f32) {
+        if !self.opened {
+            return;
+        }
+
+        self.opened &= ui
+            .window(self.get_name())
+            .opened(&mut self.opened)
+            .resizable(true)
+            .collapsible(true)
+            .movable(true)
+            .build(|| {
+                ui.set_window_font_scale(1.2);
+
+                if ui.is_window_focused() && ui.is_key_down(imgui::Key::Escape) {
+                    return false;
+                }
+
+                // Layers are hidden only in the emulator output, LCDC is left unchanged.
+                ui.checkbox("Background", &mut emulator.ppu.layers.background);
+                ui.same_line();
+                ui.checkbox("Window", &mut emulator.ppu.layers.window);
+                ui.same_line();
+                ui.checkbox("Objects", &mut emulator.ppu.layers.objects);
+
+                let mut separate_buffers = emulator.ppu.layer_buffers.is_some();
+                if ui.checkbox("Render layers separately", &mut separate_buffers) {
+                    emulator.ppu.layer_buffers = separate_buffers.then(LayerBuffers::default);
+                }
+
+                if emulator.ppu.layer_buffers.is_none() {
+                    return true;
+                }
+
+                let size = [
+                    ppu::SCREEN_WIDTH as f32 * IMAGE_SCALE,
+                    ppu::SCREEN_HEIGHT as f32 * IMAGE_SCALE,
+                ];
+
+                for (name, framebuffer) in [
+                    ("Background", &self.background),
+                    ("Window", &self.window),
+                    ("Objects", &self.objects),
+                ] {
+                    ui.group(|| {
+                        ui.text(name);
+
+                        let cursor_pos = ui.cursor_screen_pos();
+                        ui.get_window_draw_list()
+                            .add_image(
+                                TextureId::new(framebuffer.get_texture_id() as _),
+                                cursor_pos,
+                                [cursor_pos[0] + size[0], cursor_pos[1] + size[1]],
+                            )
+                            .build();
+                        ui.dummy(size);
+                    });
+                    ui.same_line();
+                }
+                ui.new_line();
+
+                true
+            })
+            .unwrap_or(true);
+    }
+
+    fn is_opened(&self) -> bool {
+        self.opened
+    }
+
+    fn set_opened(&mut self, opened: bool) {
+        self.opened = opened;
+    }
+
+    fn get_name(&self) -> &'static str {
+        "Layers"
+    }
+}
@@ -9,6 +9,10 @@ use gameboy::Gameboy;
 
 use super::{GoToLinePopup, Panel};
 
+// Labels of a row are written after its characters, the ones that don't fit are cut off.
+const LABELS_START: usize = 57;
+const LINE_LENGTH: usize = LABELS_START + 64;
+
 pub struct MemoryPanel {
     go_to_line_popup: GoToLinePopup,
 }
@@ -28,11 +32,13 @@ impl MemoryPanel {
         let file = File::create(path)?;
         let mut file = BufWriter::new(file);
 
-        for i in 0..0x10000usize {
-            let mut line = [' ' as u8; 58];
-            Self::format_line(emulator, &mut line, i as _);
-            line[line.len() - 1] = '\n' as u8;
-            file.write(&line)?;
+        for i in 0..0x1000usize {
+            let mut line = [' ' as u8; LINE_LENGTH];
+            writeln!(
+                file,
+                "{}",
+                Self::format_line(emulator, &mut line, i as _).trim_end()
+            )?;
         }
 
         Ok(())
@@ -57,6 +63,15 @@ impl MemoryPanel {
             line[39 + i] = character as u8;
         }
 
+        let mut labels = &mut line[LABELS_START..];
+        for i in 0..16 {
+            let _ = match emulator.label_at(index + i) {
+                Some(label) if i == 0 => write!(labels, "{label} "),
+                Some(label) => write!(labels, "+{i:x}:{label} "),
+                None => Ok(()),
+            };
+        }
+
         unsafe { std::str::from_utf8_unchecked(line) }
     }
 
@@ -91,6 +106,7 @@ impl Panel for MemoryPanel {
                     ui,
                     |scroll| Self::round((scroll * 0xFFF0 as f32) as u32),
                     |line| Self::round(line) as f32 / 0xFFF0 as f32,
+                    |name| super::find_label(emulator, name),
                 );
 
                 // Use a list clipper for efficient rendering.
@@ -100,7 +116,7 @@ impl Panel for MemoryPanel {
                     .begin(ui);
 
                 clipper.iter().for_each(|current_row| {
-                    let mut line = [' ' as u8; 57];
+                    let mut line = [' ' as u8; LINE_LENGTH];
                     ui.text(Self::format_line(emulator, &mut line, current_row as _));
                 });
             });
@@ -2,6 +2,7 @@ pub mod bg_map;
 pub mod debugger;
 pub mod io_map;
 pub mod keyboard_map;
+pub mod layers;
 pub mod memory;
 pub mod registers;
 
@@ -11,7 +12,8 @@ use gameboy::Gameboy;
 
 use self::{
     bg_map::BgMapPanel, debugger::DebuggerPanel, io_map::IoMapPanel,
-    keyboard_map::KeyboardMapPanel, memory::MemoryPanel, registers::RegistersPanel,
+    keyboard_map::KeyboardMapPanel, layers::LayersPanel, memory::MemoryPanel,
+    registers::RegistersPanel,
 };
 
 pub trait Panel {
@@ -38,6 +40,7 @@ pub struct Panels {
     pub keyboard_map: KeyboardMapPanel,
     pub io_map: IoMapPanel,
     pub bg_map: BgMapPanel,
+    pub layers: LayersPanel,
 }
 
 impl Panels {
@@ -50,6 +53,7 @@ impl Panels {
             keyboard_map: KeyboardMapPanel::new(),
             io_map: IoMapPanel::new(),
             bg_map: BgMapPanel::new(),
+            layers: LayersPanel::new(),
         }
     }
 }
@@ -59,6 +63,7 @@ macro_rules! call_small_panels {
         $panels.keyboard_map.$function($($arguments,)*);
         $panels.io_map.$function($($arguments,)*);
         $panels.bg_map.$function($($arguments,)*);
+        $panels.layers.$function($($arguments,)*);
     };
 }
 
@@ -103,11 +108,27 @@ pub fn format_in_place<T: Into<u32>>(buffer: &mut [u8], value: T, index: usize)
     }
 }
 
+// Address of a label for the go to line popups, None when there is no label with the name.
+// Labels in the switchable rom area are only found when their bank is mapped.
+pub fn find_label(emulator: &Gameboy, name: &str) -> Option<Result<u32, String>> {
+    let (bank, address) = emulator.symbols.find(name)?;
+
+    let mapped_bank = emulator.memory_map.bank_at(address);
+    if (0x4000..0x8000).contains(&address) && bank != mapped_bank {
+        return Some(Err(format!(
+            "{name} is in bank {bank:02x}, bank {mapped_bank:02x} is mapped."
+        )));
+    }
+
+    Some(Ok(address as u32))
+}
+
 pub struct GoToLinePopup {
     name: &'static str,
     is_open: bool,
     is_initial_run: bool,
-    line: u32,
+    text: String,
+    error: Option<String>,
 }
 
 impl GoToLinePopup {
@@ -116,15 +137,18 @@ impl GoToLinePopup {
             name,
             is_open: false,
             is_initial_run: true,
-            line: 0,
+            text: String::new(),
+            error: None,
         }
     }
 
+    // Line is given with the name of a label or in hexadecimal, with an optional $ prefix.
     pub fn render(
         &mut self,
         ui: &imgui::Ui,
         scroll_to_line: impl FnOnce(f32) -> u32,
         line_to_scroll: impl FnOnce(u32) -> f32,
+        find_label: impl FnOnce(&str) -> Option<Result<u32, String>>,
     ) {
         if ui.is_window_focused()
             && ui.is_key_down(imgui::Key::LeftCtrl)
@@ -137,7 +161,8 @@ impl GoToLinePopup {
             ui.open_popup(self.name);
             self.is_open = true;
             self.is_initial_run = true;
-            self.line = scroll_to_line(ui.scroll_y() / ui.scroll_max_y());
+            self.text = format!("{:02x}", scroll_to_line(ui.scroll_y() / ui.scroll_max_y()));
+            self.error = None;
         }
 
         if let Some(line) = ui
@@ -150,15 +175,35 @@ impl GoToLinePopup {
                     self.is_initial_run = false;
                 }
 
-                ui.set_next_item_width(100.0);
+                ui.set_next_item_width(200.0);
 
-                hex_input_u8(ui, "Line", &mut self.line);
+                ui.input_text("Line", &mut self.text).build();
 
-                if ui.button("Go") || ui.is_key_down(imgui::Key::Enter) {
-                    ui.close_current_popup();
-                    self.is_open = false;
+                if let Some(error) = &self.error {
+                    ui.text_colored([1.0, 0.0, 0.0, 1.0], error);
+                }
 
-                    return Some(self.line);
+                if ui.button("Go") || ui.is_key_down(imgui::Key::Enter) {
+                    // Labels like Fade are valid hexadecimal too, a $ prefix always means an address.
+                    let text = self.text.trim();
+                    let line = match text.strip_prefix('$') {
+                        Some(hex) => u32::from_str_radix(hex, 16)
+                            .map_err(|_| format!("Invalid address {text}.")),
+                        None => find_label(text).unwrap_or_else(|| {
+                            u32::from_str_radix(text, 16)
+                                .map_err(|_| format!("Unknown label {text}."))
+                        }),
+                    };
+
+                    match line {
+                        Ok(line) => {
+                            ui.close_current_popup();
+                            self.is_open = false;
+
+                            return Some(line);
+                        }
+                        Err(error) => self.error = Some(error),
+                    }
                 }
                 ui.same_line();
                 if ui.button("Close") {